@@ -4,14 +4,15 @@ use anchor_lang::prelude::*;
 pub const ONE_TOKEN: u64 = 1_000_000_000;
 
 /// Initial reward per round: 20 RUBY (Days 0-3)
-/// After Day 3: decreases by 2 RUBY per day until reaching 6 RUBY
-/// 2 months after hitting 6 RUBY: decreases by 1 RUBY per month
-/// Minimum: 1 RUBY per block until all tokens mined
+/// The full curve lives in the EmissionSchedule account.
 pub const INITIAL_REWARD_PER_ROUND: u64 = ONE_TOKEN * 20;
 
 /// Minimum reward per round: 1 RUBY
 pub const MIN_REWARD_PER_ROUND: u64 = ONE_TOKEN;
 
+/// One SOL, denominated in lamports.
+pub const ONE_SOL: u64 = 1_000_000_000;
+
 /// The number of slots for deployment phase (1 minute = 150 slots).
 pub const DEPLOYMENT_WINDOW_SLOTS: u64 = 150;
 
@@ -21,6 +22,9 @@ pub const TWELVE_HOURS_SLOTS: u64 = 108_000;
 /// The number of slots in one day.
 pub const ONE_DAY_SLOTS: u64 = 216_000;
 
+/// The number of slots in one week (leaderboard epoch).
+pub const ONE_WEEK_SLOTS: u64 = ONE_DAY_SLOTS * 7;
+
 /// The number of slots for breather between rounds.
 pub const INTERMISSION_SLOTS: u64 = 10;
 
@@ -30,6 +34,12 @@ pub const MAX_SUPPLY: u64 = ONE_TOKEN * 6_000_000;
 /// Motherlode increment per round: +3 RUBY
 pub const MOTHERLODE_INCREMENT: u64 = ONE_TOKEN * 3;
 
+/// Default SOL motherlode odds: 1 in 400 rounds
+pub const MOTHERLODE_SOL_ODDS: u64 = 400;
+
+/// Minimum SOL motherlode odds when enabled: 1 in 100 rounds
+pub const MIN_MOTHERLODE_SOL_ODDS: u64 = 100;
+
 /// The seed of the automation account PDA.
 pub const AUTOMATION: &[u8] = b"automation";
 
@@ -39,12 +49,27 @@ pub const BOARD: &[u8] = b"board";
 /// The seed of the config account PDA.
 pub const CONFIG: &[u8] = b"config";
 
+/// The seed of the executor registry account PDA.
+pub const EXECUTOR: &[u8] = b"executor";
+
 /// The seed of the miner account PDA.
 pub const MINER: &[u8] = b"miner";
 
 /// The seed of the round account PDA.
 pub const ROUND: &[u8] = b"round";
 
+/// The seed of the pending config change account PDA.
+pub const PENDING_CONFIG_CHANGE: &[u8] = b"pending_config_change";
+
+/// Minimum delay before a queued config change can be executed (1 day).
+pub const MIN_CONFIG_CHANGE_DELAY_SLOTS: u64 = ONE_DAY_SLOTS;
+
+/// The seed of the presale account PDA.
+pub const PRESALE: &[u8] = b"presale";
+
+/// The seed of the presale contribution account PDA.
+pub const PRESALE_CONTRIBUTION: &[u8] = b"presale_contribution";
+
 /// The seed of the referral account PDA.
 pub const REFERRAL: &[u8] = b"referral";
 
@@ -54,12 +79,40 @@ pub const STAKE: &[u8] = b"stake";
 /// The seed of the treasury account PDA.
 pub const TREASURY: &[u8] = b"treasury";
 
+/// The seed of the emission schedule account PDA.
+pub const EMISSION_SCHEDULE: &[u8] = b"emission_schedule";
+
+/// The maximum number of segments in the emission schedule.
+pub const MAX_EMISSION_SEGMENTS: usize = 32;
+
 /// The seed of the leaderboard account PDA.
 pub const LEADERBOARD: &[u8] = b"leaderboard";
 
+/// The number of ranked deployers tracked on each weekly leaderboard.
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// Default leaderboard payout weights by rank, in basis points (sum = 100%).
+pub const LEADERBOARD_WEIGHTS_BPS: [u64; LEADERBOARD_SIZE] =
+    [3000, 2000, 1500, 1000, 800, 600, 400, 300, 250, 150];
+
 /// Referral fee in basis points (100 = 1%)
 pub const REFERRAL_FEE_BPS: u64 = 100;
 
+/// The seed of the referral config account PDA.
+pub const REFERRAL_CONFIG: &[u8] = b"referral_config";
+
+/// The seed of the referrer stats account PDA.
+pub const REFERRER_STATS: &[u8] = b"referrer_stats";
+
+/// The number of referral tiers.
+pub const REFERRAL_TIERS: usize = 4;
+
+/// Maximum referral rate for any tier or level, in basis points (10%).
+pub const MAX_REFERRAL_BPS: u64 = 1_000;
+
+/// Default window after registering in which a referee may change referrer (7 days, in seconds).
+pub const REFERRAL_CHANGE_WINDOW: i64 = 7 * 24 * 60 * 60;
+
 /// The fee paid to bots if they checkpoint a user (0.00001 SOL).
 pub const CHECKPOINT_FEE: u64 = 10_000;
 
@@ -69,6 +122,24 @@ pub const MIN_DEPLOYMENT: u64 = 1_000;
 /// The minimum stake amount (1 RUBY).
 pub const MIN_STAKE_AMOUNT: u64 = ONE_TOKEN;
 
+/// Maximum number of lock positions per stake account.
+pub const MAX_STAKE_LOCKS: usize = 8;
+
+/// Length of one lock month in seconds (30 days).
+pub const STAKE_LOCK_MONTH: i64 = 30 * 24 * 60 * 60;
+
+/// Maximum number of pending unbonding entries per stake account.
+pub const MAX_UNBONDING_ENTRIES: usize = 8;
+
+/// Default cooldown between stake_unbond and stake_withdraw_unbonded (7 days).
+pub const STAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+/// Minimum configurable stake cooldown (1 day).
+pub const MIN_STAKE_COOLDOWN: i64 = 24 * 60 * 60;
+
+/// Maximum configurable stake cooldown (30 days).
+pub const MAX_STAKE_COOLDOWN: i64 = 30 * 24 * 60 * 60;
+
 /// The address of the mint account (RUBY token - placeholder)
 pub const MINT_ADDRESS: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 
@@ -82,6 +153,7 @@ pub const SPLIT_ADDRESS: Pubkey = pubkey!("SpLiT11111111111111111111111111111111
 pub const TOTAL_BOARD: usize = 25;
 
 // ===== RUBY Fee Distribution =====
+// Defaults for Config::fee_schedule (admin can change via set_fee_schedule)
 // Total from losing squares: 100%
 // - 88% to winners
 // - 8% buyback and burn
@@ -102,3 +174,72 @@ pub const MOTHERLODE_BPS: u64 = 200;
 
 /// Platform fee: 1% of winners amount
 pub const PLATFORM_FEE_BPS: u64 = 100;
+
+/// Staking share: 0% by default (set via fee schedule)
+pub const STAKE_BPS: u64 = 0;
+
+/// The current layout version of the config account.
+/// 1: fee schedule. 2: guardian, pause flags, pending admin and stake cooldown.
+pub const CONFIG_VERSION: u64 = 2;
+
+/// The current layout version of the board account.
+/// 1: SOL motherlode odds and history, last winning square and version.
+pub const BOARD_VERSION: u64 = 1;
+
+/// The current layout version of the treasury account.
+/// 1: stake rewards balance, stake weight, presale reservation, leaderboard carry and version.
+pub const TREASURY_VERSION: u64 = 1;
+
+/// The current layout version of the stake account.
+/// 1: weighted balance, lock positions, reward timestamp and version. 2: unbonding queue.
+pub const STAKE_VERSION: u64 = 2;
+
+/// Current Automation account layout version.
+/// 1: square count and rotation. 2: limits, auto-checkpoint, status and open market.
+pub const AUTOMATION_VERSION: u64 = 2;
+
+// ===== Pause Flags (Config::pause_flags) =====
+
+/// Pause deploy and automation setup.
+pub const PAUSE_DEPLOY: u64 = 1 << 0;
+
+/// Pause reset and close_round.
+pub const PAUSE_RESET: u64 = 1 << 1;
+
+/// Pause checkpoint.
+pub const PAUSE_CHECKPOINT: u64 = 1 << 2;
+
+/// Pause RUBY claims (claim_token, referral rewards, leaderboard, presale RUBY).
+pub const PAUSE_CLAIM: u64 = 1 << 3;
+
+/// Pause stake_deposit and stake_claim.
+pub const PAUSE_STAKE: u64 = 1 << 4;
+
+/// Pause bury.
+pub const PAUSE_BURY: u64 = 1 << 5;
+
+/// Pause presale contributions.
+pub const PAUSE_PRESALE: u64 = 1 << 6;
+
+/// Pause withdrawals (claim_sol, stake_unbond, stake_withdraw_unbonded, cancel_automate, close, presale refunds).
+pub const PAUSE_WITHDRAW: u64 = 1 << 7;
+
+/// Global pause. Withdrawals stay available unless PAUSE_WITHDRAW is also set.
+pub const PAUSE_ALL: u64 = PAUSE_DEPLOY
+    | PAUSE_RESET
+    | PAUSE_CHECKPOINT
+    | PAUSE_CLAIM
+    | PAUSE_STAKE
+    | PAUSE_BURY
+    | PAUSE_PRESALE;
+
+// ===== RUBY Presale =====
+
+/// Maximum presale contribution per wallet: 5 SOL
+pub const PRESALE_WALLET_CAP: u64 = ONE_SOL * 5;
+
+/// Total presale cap: 200 SOL
+pub const PRESALE_TOTAL_CAP: u64 = ONE_SOL * 200;
+
+/// Presale rate: 1 SOL = 200 RUBY (both use 9 decimals, so lamports map 1:200 to token units)
+pub const PRESALE_RATE: u64 = 200;
@@ -70,4 +70,115 @@ pub enum AppError {
 
     #[msg("Invalid referral account")]
     InvalidReferral,
+
+    #[msg("Presale is not accepting contributions")]
+    PresaleNotActive,
+
+    #[msg("Presale contribution window has not ended")]
+    PresaleStillActive,
+
+    #[msg("Presale has already been finalized or cancelled")]
+    PresaleAlreadySettled,
+
+    #[msg("Presale has not been finalized or cancelled")]
+    PresaleNotSettled,
+
+    #[msg("Presale per-wallet cap exceeded")]
+    PresaleWalletCapExceeded,
+
+    #[msg("Presale total cap exceeded")]
+    PresaleCapExceeded,
+
+    #[msg("Maximum token supply reached")]
+    MaxSupplyReached,
+
+    #[msg("Invalid leaderboard weights")]
+    InvalidLeaderboardWeights,
+
+    #[msg("Leaderboard week has not ended")]
+    LeaderboardNotEnded,
+
+    #[msg("Leaderboard already settled")]
+    LeaderboardAlreadySettled,
+
+    #[msg("Invalid leaderboard recipient")]
+    InvalidLeaderboardRecipient,
+
+    #[msg("Invalid round account")]
+    InvalidRound,
+
+    #[msg("Round has not expired")]
+    RoundNotExpired,
+
+    #[msg("Invalid fee schedule")]
+    InvalidFeeSchedule,
+
+    #[msg("Invalid emission segment")]
+    InvalidEmissionSegment,
+
+    #[msg("Emission schedule is full")]
+    EmissionScheduleFull,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Invalid config change")]
+    InvalidConfigChange,
+
+    #[msg("Config change timelock has not elapsed")]
+    ConfigChangeLocked,
+
+    #[msg("Square cannot be topped up again this round")]
+    TopUpLimitReached,
+
+    #[msg("Deploy did not move any SOL")]
+    NothingDeployed,
+
+    #[msg("Automation already deployed this round")]
+    AutomationAlreadyExecuted,
+
+    #[msg("Automation has expired")]
+    AutomationExpired,
+
+    #[msg("Automation round limit reached")]
+    AutomationLimitReached,
+
+    #[msg("Automation stop-loss reached")]
+    AutomationStopLoss,
+
+    #[msg("Automation is not active")]
+    AutomationNotActive,
+
+    #[msg("Invalid referral config")]
+    InvalidReferralConfig,
+
+    #[msg("Referrer can no longer be changed")]
+    ReferralChangeNotAllowed,
+
+    #[msg("Referral has pending rewards")]
+    ReferralHasPendingRewards,
+
+    #[msg("Invalid stake lock period")]
+    InvalidLockPeriod,
+
+    #[msg("Stake is locked")]
+    StakeLocked,
+
+    #[msg("Stake lock limit reached")]
+    StakeLockLimitReached,
+
+    #[msg("Unbonding limit reached")]
+    UnbondingLimitReached,
+
+    #[msg("Invalid stake cooldown")]
+    InvalidStakeCooldown,
+
+    #[msg("No unbonded stake is past its cooldown")]
+    StakeCooldownActive,
+
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+
+    #[msg("Invalid SOL motherlode odds")]
+    InvalidMotherlodeOdds,
 }
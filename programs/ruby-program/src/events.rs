@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::constants::{REFERRAL_TIERS, TOTAL_BOARD};
+use crate::state::{CheckpointResult, ConfigField, ConfigValue, EmissionSegment, FeeSchedule};
 
 /// Event emitted when reset instruction is executed
 #[event]
@@ -24,6 +26,15 @@ pub struct ResetEvent {
     /// The amount of TOKEN payout for the motherlode
     pub motherlode: u64,
 
+    /// The amount of SOL payout for the mini-motherlode
+    pub mini_motherlode_sol: u64,
+
+    /// The amount of SOL payout for the SOL motherlode
+    pub motherlode_sol: u64,
+
+    /// The SOL motherlode pool balance after this round
+    pub motherlode_sol_pool: u64,
+
     /// The total amount of SOL deployed in the round
     pub total_deployed: u64,
 
@@ -36,6 +47,9 @@ pub struct ResetEvent {
     /// The total amount of TOKEN minted for the round
     pub total_minted: u64,
 
+    /// The fee schedule applied to the round
+    pub fee_schedule: FeeSchedule,
+
     /// Unix timestamp when event occurred
     pub timestamp: i64,
 }
@@ -49,12 +63,15 @@ pub struct DeployEvent {
     /// The round id
     pub round_id: u64,
 
-    /// The amount of SOL deployed per square
+    /// The amount of SOL deployed per square (0 for weighted deploys)
     pub amount: u64,
 
-    /// The number of squares deployed to
+    /// The requested square mask (0 for weighted deploys)
     pub squares: u32,
 
+    /// SOL deployed to each square by this transaction
+    pub deployed: [u64; TOTAL_BOARD],
+
     /// The total cumulative SOL deployed in the round
     pub total_deployed: u64,
 
@@ -90,13 +107,57 @@ pub struct CheckpointEvent {
     pub timestamp: i64,
 }
 
+/// Event emitted when checkpoint_many instruction is executed
+#[event]
+pub struct CheckpointManyEvent {
+    /// The round checkpointed against
+    pub round_id: u64,
+
+    /// The crank that sent the batch
+    pub signer: Pubkey,
+
+    /// Total bot fees paid to the signer
+    pub total_bot_fee: u64,
+
+    /// Outcome for each miner, in the order given
+    pub results: Vec<CheckpointResult>,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when settle instruction is executed
+#[event]
+pub struct SettleEvent {
+    /// The miner authority
+    pub authority: Pubkey,
+
+    /// The miner's checkpoint id after settling
+    pub checkpoint_id: u64,
+
+    /// SOL claimed
+    pub sol_amount: u64,
+
+    /// The account that received the SOL
+    pub sol_recipient: Pubkey,
+
+    /// TOKEN claimed
+    pub token_amount: u64,
+
+    /// The token account that received the TOKEN (default if not claimed)
+    pub token_recipient: Pubkey,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
 /// Event emitted when bury instruction is executed
 #[event]
 pub struct BuryEvent {
     /// Amount of TOKEN burned
     pub token_burned: u64,
 
-    /// Amount of TOKEN shared with stakers (0 in Anchor version - no staking)
+    /// Amount of TOKEN shared with stakers (always 0 - their share is paid in SOL at reset)
     pub token_shared: u64,
 
     /// Amount of SOL swapped
@@ -125,7 +186,26 @@ pub struct StakeDepositEvent {
     pub total_staked: u64,
 }
 
-/// Event emitted when stake_withdraw instruction is executed
+/// Event emitted when stake_unbond instruction is executed
+#[event]
+pub struct StakeUnbondEvent {
+    /// The authority of the staker
+    pub authority: Pubkey,
+
+    /// The amount of TOKEN unbonding
+    pub amount: u64,
+
+    /// The timestamp from which the amount can be withdrawn
+    pub available_at: i64,
+
+    /// The remaining staked balance
+    pub balance: u64,
+
+    /// The total staked across all users
+    pub total_staked: u64,
+}
+
+/// Event emitted when stake_withdraw_unbonded instruction is executed
 #[event]
 pub struct StakeWithdrawEvent {
     /// The authority of the staker
@@ -157,6 +237,50 @@ pub struct StakeClaimEvent {
     pub lifetime_rewards: u64,
 }
 
+/// Event emitted when stake_lock instruction is executed
+#[event]
+pub struct StakeLockedEvent {
+    /// The authority of the staker
+    pub authority: Pubkey,
+
+    /// The lock position slot
+    pub index: u64,
+
+    /// The amount of TOKEN locked
+    pub amount: u64,
+
+    /// The lock period (StakeLockPeriod)
+    pub period: u64,
+
+    /// The timestamp at which the position unlocks
+    pub unlock_at: i64,
+
+    /// Reward weight of the position, in basis points
+    pub multiplier_bps: u64,
+
+    /// The staker's new weighted balance
+    pub weighted_balance: u64,
+
+    /// The total stake weight across all users
+    pub total_stake_weight: u64,
+}
+
+/// Event emitted when an expired lock position is released
+#[event]
+pub struct StakeUnlockedEvent {
+    /// The authority of the staker
+    pub authority: Pubkey,
+
+    /// The lock position slot
+    pub index: u64,
+
+    /// The amount of TOKEN unlocked
+    pub amount: u64,
+
+    /// The timestamp at which the position unlocked
+    pub unlock_at: i64,
+}
+
 /// Event emitted when referral is registered
 #[event]
 pub struct ReferralRegisteredEvent {
@@ -186,6 +310,63 @@ pub struct ReferralRewardAccruedEvent {
     pub timestamp: i64,
 }
 
+/// Event emitted when the referral config is set
+#[event]
+pub struct ReferralConfigSetEvent {
+    /// Referred volume needed to reach each tier
+    pub tier_thresholds: [u64; REFERRAL_TIERS],
+
+    /// Referrer rate for each tier, in basis points
+    pub tier_bps: [u64; REFERRAL_TIERS],
+
+    /// Second-level referrer rate, in basis points
+    pub second_level_bps: u64,
+
+    /// Seconds in which a referee may change referrer once
+    pub change_window: i64,
+
+    /// Referrer credited for miners without a referral
+    pub protocol_referrer: Pubkey,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when a referrer moves to a new referral tier
+#[event]
+pub struct ReferralTierChangedEvent {
+    /// The referrer
+    pub referrer: Pubkey,
+
+    /// The previous tier
+    pub old_tier: u64,
+
+    /// The new tier
+    pub new_tier: u64,
+
+    /// The referrer's total referred volume
+    pub referred_volume: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when a referee changes referrer within the grace window
+#[event]
+pub struct ReferrerChangedEvent {
+    /// The referee
+    pub authority: Pubkey,
+
+    /// The previous referrer
+    pub old_referrer: Pubkey,
+
+    /// The new referrer
+    pub new_referrer: Pubkey,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
 /// Event emitted when referrer claims their referral rewards
 #[event]
 pub struct ReferralRewardClaimedEvent {
@@ -195,9 +376,306 @@ pub struct ReferralRewardClaimedEvent {
     /// Total amount of TOKEN claimed
     pub amount: u64,
 
-    /// Number of referees claimed from
+    /// Number of referees claimed from (referee count for aggregate claims)
     pub num_referees: u64,
 
     /// Unix timestamp when event occurred
     pub timestamp: i64,
 }
+
+/// Event emitted when the presale is opened
+#[event]
+pub struct PresaleOpenedEvent {
+    /// The slot at which contributions open
+    pub start_slot: u64,
+
+    /// The slot at which contributions close
+    pub end_slot: u64,
+
+    /// The address that receives the raised SOL
+    pub liquidity_recipient: Pubkey,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when a wallet contributes to the presale
+#[event]
+pub struct PresaleContributionEvent {
+    /// The contributor
+    pub authority: Pubkey,
+
+    /// The amount of SOL contributed in this transaction
+    pub amount: u64,
+
+    /// The contributor's total contribution
+    pub total_contributed: u64,
+
+    /// The total SOL raised across all contributors
+    pub total_raised: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when the presale is finalized or cancelled
+#[event]
+pub struct PresaleFinalizedEvent {
+    /// Whether the presale was cancelled (contributors are refunded)
+    pub cancelled: bool,
+
+    /// The total SOL raised
+    pub total_raised: u64,
+
+    /// The amount of SOL sent to the liquidity recipient
+    pub liquidity_amount: u64,
+
+    /// The number of unique contributors
+    pub contributors: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when a contributor claims RUBY or a refund
+#[event]
+pub struct PresaleClaimEvent {
+    /// The contributor
+    pub authority: Pubkey,
+
+    /// The amount of SOL contributed
+    pub contributed: u64,
+
+    /// The amount of RUBY minted (0 if refunded)
+    pub token_amount: u64,
+
+    /// The amount of SOL refunded (0 if RUBY was minted)
+    pub refund_amount: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when a weekly leaderboard is settled
+#[event]
+pub struct LeaderboardSettledEvent {
+    /// The leaderboard epoch
+    pub epoch: u64,
+
+    /// The week's payout pool
+    pub pool: u64,
+
+    /// The total amount of SOL paid to ranked deployers
+    pub total_paid: u64,
+
+    /// The ranked deployers, in order
+    pub winners: Vec<Pubkey>,
+
+    /// The SOL paid to each ranked deployer, in order
+    pub payouts: Vec<u64>,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when an expired round account is closed
+#[event]
+pub struct RoundClosedEvent {
+    /// The round that was closed
+    pub round_id: u64,
+
+    /// Unclaimed SOL swept into the treasury buyback balance
+    pub unclaimed_swept: u64,
+
+    /// TOKEN minted for the round that no checkpoint credited, burned
+    pub unclaimed_token_burned: u64,
+
+    /// Rent returned to the rent payer
+    pub rent_returned: u64,
+
+    /// The account that received the rent
+    pub rent_payer: Pubkey,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when round revenue is routed to stakers
+#[event]
+pub struct StakeRewardsEvent {
+    /// The round the rewards came from
+    pub round_id: u64,
+
+    /// The amount of SOL added to staking rewards
+    pub amount: u64,
+
+    /// The total staked when the rewards were distributed
+    pub total_staked: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when a new fee schedule is queued
+#[event]
+pub struct FeeScheduleSetEvent {
+    /// The queued fee schedule
+    pub fee_schedule: FeeSchedule,
+
+    /// The first round the fee schedule applies to
+    pub effective_round: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when a segment is appended to the emission schedule
+#[event]
+pub struct EmissionSegmentAddedEvent {
+    /// The index of the new segment
+    pub index: u64,
+
+    /// The appended segment
+    pub segment: EmissionSegment,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when the pause flags change
+#[event]
+pub struct PauseChangedEvent {
+    /// The admin or guardian that changed the flags
+    pub authority: Pubkey,
+
+    /// The previous pause flags
+    pub previous_flags: u64,
+
+    /// The new pause flags
+    pub pause_flags: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when an authority or parameter in the config changes
+#[event]
+pub struct ConfigChangedEvent {
+    /// The field that changed
+    pub field: ConfigField,
+
+    /// The previous value
+    pub old_value: ConfigValue,
+
+    /// The new value
+    pub new_value: ConfigValue,
+
+    /// The signer that made the change
+    pub authority: Pubkey,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when a timelocked config change is queued
+#[event]
+pub struct ConfigChangeQueuedEvent {
+    /// The field to change
+    pub field: ConfigField,
+
+    /// The current value
+    pub old_value: ConfigValue,
+
+    /// The queued value
+    pub new_value: ConfigValue,
+
+    /// The first slot at which the change can be executed
+    pub executable_at: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when a queued config change is cancelled
+#[event]
+pub struct ConfigChangeCancelledEvent {
+    /// The field that was queued
+    pub field: ConfigField,
+
+    /// The value that was queued
+    pub new_value: ConfigValue,
+
+    /// The admin or guardian that cancelled
+    pub authority: Pubkey,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when an automation runs out of balance
+#[event]
+pub struct AutomationDepletedEvent {
+    /// The automation authority
+    pub authority: Pubkey,
+
+    /// Leftover balance refunded to the authority
+    pub balance_refunded: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when an automation account is closed
+#[event]
+pub struct AutomationClosedEvent {
+    /// The automation authority
+    pub authority: Pubkey,
+
+    /// Unspent balance refunded to the authority
+    pub balance_refunded: u64,
+
+    /// Account rent refunded to the authority
+    pub rent_refunded: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when an automation's executor is rotated
+#[event]
+pub struct AutomationExecutorChangedEvent {
+    /// The automation authority
+    pub authority: Pubkey,
+
+    /// The previous executor
+    pub old_executor: Pubkey,
+
+    /// The new executor
+    pub new_executor: Pubkey,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when an executor registers or updates its fee
+#[event]
+pub struct ExecutorRegisteredEvent {
+    /// The executor's signing key
+    pub executor: Pubkey,
+
+    /// The published fee per deploy
+    pub fee: u64,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
+
+/// Event emitted when an executor leaves the registry
+#[event]
+pub struct ExecutorUnregisteredEvent {
+    /// The executor's signing key
+    pub executor: Pubkey,
+
+    /// Unix timestamp when event occurred
+    pub timestamp: i64,
+}
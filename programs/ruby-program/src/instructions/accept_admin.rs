@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ConfigChangedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump,
+        has_one = pending_admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let new_admin = ctx.accounts.pending_admin.key();
+    require!(new_admin != Pubkey::default(), AppError::NotAuthorized);
+
+    let old_value = config.admin;
+    config.admin = new_admin;
+    config.pending_admin = Pubkey::default();
+
+    emit!(ConfigChangedEvent {
+        field: ConfigField::Admin,
+        old_value: ConfigValue::Pubkey(old_value),
+        new_value: ConfigValue::Pubkey(new_admin),
+        authority: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
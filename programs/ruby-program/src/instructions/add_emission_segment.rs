@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::EmissionSegmentAddedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AddEmissionSegment<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [BOARD],
+        bump,
+    )]
+    pub board: Account<'info, Board>,
+
+    #[account(
+        mut,
+        seeds = [EMISSION_SCHEDULE],
+        bump,
+    )]
+    pub emission_schedule: Box<Account<'info, EmissionSchedule>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddEmissionSegmentArgs {
+    pub segment: EmissionSegment,
+}
+
+/// Handler for add_emission_segment instruction
+///
+/// Segments are append-only and must start in the future, so rewards already
+/// emitted under the current curve are never rewritten.
+pub fn handler(ctx: Context<AddEmissionSegment>, args: AddEmissionSegmentArgs) -> Result<()> {
+    let clock = Clock::get()?;
+    let emission_schedule = &mut ctx.accounts.emission_schedule;
+    let segment = args.segment;
+
+    require!(
+        (emission_schedule.len as usize) < MAX_EMISSION_SEGMENTS,
+        AppError::EmissionScheduleFull
+    );
+    require!(segment.is_valid(), AppError::InvalidEmissionSegment);
+
+    // Must start after the current slot and after the last segment
+    let current_offset = clock.slot.saturating_sub(ctx.accounts.board.mining_start_slot);
+    require!(
+        segment.start_slot_offset > current_offset,
+        AppError::InvalidEmissionSegment
+    );
+    if let Some(last) = emission_schedule.segments().last() {
+        require!(
+            segment.start_slot_offset > last.start_slot_offset,
+            AppError::InvalidEmissionSegment
+        );
+    }
+
+    let index = emission_schedule.len;
+    emission_schedule.push(segment);
+
+    emit!(EmissionSegmentAddedEvent {
+        index,
+        segment,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Emission segment {} added at slot offset {}: reward {}, step {} every {} slots, floor {}",
+        index, segment.start_slot_offset, segment.reward, segment.step, segment.step_interval, segment.floor);
+
+    Ok(())
+}
@@ -2,13 +2,20 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::AppError;
 use crate::state::*;
-use crate::utils::{transfer_lamports, transfer_sol_cpi};
+use crate::utils::transfer_sol_cpi;
+use super::cancel_automate::close_automation;
 
 #[derive(Accounts)]
 pub struct Automate<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         init_if_needed,
         payer = authority,
@@ -41,32 +48,43 @@ pub struct AutomateArgs {
     pub fee: u64,
     pub mask: u64,
     pub strategy: u8,
+    /// Squares per round for count-based strategies (1-25)
+    pub square_count: u64,
+    /// Squares to rotate the mask by each round (Rotating strategy)
+    pub rotation_step: u64,
+    /// Maximum SOL deployed per round (0 for no cap)
+    pub max_per_round: u64,
+    /// Maximum rounds to execute (0 for no limit)
+    pub max_rounds: u64,
+    /// Slot after which the automation stops (0 for never)
+    pub expires_at: u64,
+    /// Maximum net SOL loss before the automation stops (0 to disable)
+    pub stop_loss: u64,
+    /// Checkpoint the prior round inside the executor's deploy
+    pub auto_checkpoint: bool,
+    /// Recycle SOL winnings into the automation balance (requires auto_checkpoint)
+    pub recycle_winnings: bool,
+    /// Accept any registered executor whose fee is at most max_fee
+    pub open_market: bool,
+    /// Highest registry fee to pay in open-market mode
+    pub max_fee: u64,
 }
 
 pub fn handler(ctx: Context<Automate>, args: AutomateArgs) -> Result<()> {
+    // Closing automation is a withdrawal; configuring it is part of deploying
+    let pause_flag = if ctx.accounts.executor.key() == Pubkey::default() {
+        PAUSE_WITHDRAW
+    } else {
+        PAUSE_DEPLOY
+    };
+    ctx.accounts.config.assert_not_paused(pause_flag)?;
+
     // Close automation account if executor is Pubkey::default()
     if ctx.accounts.executor.key() == Pubkey::default() {
-        // Verify authority before closing
-        require!(
-            ctx.accounts.automation.authority == ctx.accounts.authority.key(),
-            AppError::NotAuthorized
+        return close_automation(
+            &mut ctx.accounts.automation,
+            &ctx.accounts.authority.to_account_info(),
         );
-
-        // Close automation account properly:
-        let automation_info = ctx.accounts.automation.to_account_info();
-        let authority_info = ctx.accounts.authority.to_account_info();
-
-        // 1. Transfer all lamports to authority
-        let automation_lamports = automation_info.lamports();
-        transfer_lamports(&automation_info, &authority_info, automation_lamports)?;
-
-        // 2. Zero out account data (marks account as closed)
-        let mut automation_data = automation_info.try_borrow_mut_data()?;
-        automation_data.fill(0);
-
-        msg!("Automation account closed successfully");
-        msg!("  Returned {} lamports to authority", automation_lamports);
-        return Ok(());
     }
 
     // Initialize automation if needed
@@ -74,6 +92,7 @@ pub fn handler(ctx: Context<Automate>, args: AutomateArgs) -> Result<()> {
         ctx.accounts.automation.authority
          = ctx.accounts.authority.key();
         ctx.accounts.automation.balance = 0;
+        ctx.accounts.automation.last_round_executed = u64::MAX;
         msg!("Automation account initialized");
     } else {
         // Verify authority if already initialized
@@ -88,6 +107,8 @@ pub fn handler(ctx: Context<Automate>, args: AutomateArgs) -> Result<()> {
         ctx.accounts.miner.authority = ctx.accounts.authority.key();
         ctx.accounts.miner.deployed = [0; TOTAL_BOARD];
         ctx.accounts.miner.cumulative = [0; TOTAL_BOARD];
+        ctx.accounts.miner.top_up_cumulative = [0; TOTAL_BOARD];
+        ctx.accounts.miner.top_up_deployed = [0; TOTAL_BOARD];
         ctx.accounts.miner.checkpoint_fee = 0;
         ctx.accounts.miner.checkpoint_id = 0;
         ctx.accounts.miner.rewards_sol = 0;
@@ -98,6 +119,8 @@ pub fn handler(ctx: Context<Automate>, args: AutomateArgs) -> Result<()> {
         ctx.accounts.miner.lifetime_rewards_token = 0;
         ctx.accounts.miner.last_claim_token_at = 0;
         ctx.accounts.miner.last_claim_sol_at = 0;
+        ctx.accounts.miner.leaderboard_epoch = 0;
+        ctx.accounts.miner.leaderboard_deployed = 0;
         msg!("Miner account initialized");
     } else {
         // Verify miner authority
@@ -107,12 +130,43 @@ pub fn handler(ctx: Context<Automate>, args: AutomateArgs) -> Result<()> {
         );
     }
 
-    // Update automation settings
+    // Validate strategy parameters
+    let strategy = AutomationStrategy::from_u64(args.strategy as u64)?;
+    if strategy.uses_square_count() {
+        require!(
+            args.square_count > 0 && args.square_count <= TOTAL_BOARD as u64,
+            AppError::InvalidSquare
+        );
+    } else {
+        require!(
+            args.mask != 0 && args.mask < (1 << TOTAL_BOARD),
+            AppError::InvalidSquare
+        );
+    }
+
+    // Update automation settings (reactivates a depleted automation)
+    ctx.accounts.automation.version = AUTOMATION_VERSION;
+    ctx.accounts.automation.status = AutomationStatus::Active as u64;
     ctx.accounts.automation.amount = args.amount;
     ctx.accounts.automation.executor = ctx.accounts.executor.key(); // ✅ FIX: Set executor
     ctx.accounts.automation.fee = args.fee;
     ctx.accounts.automation.mask = args.mask;
     ctx.accounts.automation.strategy = args.strategy as u64;
+    ctx.accounts.automation.square_count = args.square_count;
+    ctx.accounts.automation.rotation_step = args.rotation_step;
+    ctx.accounts.automation.auto_checkpoint = args.auto_checkpoint;
+    ctx.accounts.automation.recycle_winnings = args.auto_checkpoint && args.recycle_winnings;
+    ctx.accounts.automation.open_market = args.open_market;
+    ctx.accounts.automation.max_fee = args.max_fee;
+
+    // Budgets restart from each configuration
+    ctx.accounts.automation.max_per_round = args.max_per_round;
+    ctx.accounts.automation.max_rounds = args.max_rounds;
+    ctx.accounts.automation.expires_at = args.expires_at;
+    ctx.accounts.automation.stop_loss = args.stop_loss;
+    ctx.accounts.automation.rounds_executed = 0;
+    ctx.accounts.automation.total_spent = 0;
+    ctx.accounts.automation.rewards_baseline = ctx.accounts.miner.lifetime_rewards_sol;
 
     msg!("Automation settings updated:");
     msg!("  amount: {}", ctx.accounts.automation.amount);
@@ -120,6 +174,8 @@ pub fn handler(ctx: Context<Automate>, args: AutomateArgs) -> Result<()> {
     msg!("  fee: {}", ctx.accounts.automation.fee);
     msg!("  mask: {}", ctx.accounts.automation.mask);
     msg!("  strategy: {}", ctx.accounts.automation.strategy);
+    msg!("  square_count: {}", ctx.accounts.automation.square_count);
+    msg!("  rotation_step: {}", ctx.accounts.automation.rotation_step);
 
     // Add deposit to automation balance
     if args.deposit > 0 {
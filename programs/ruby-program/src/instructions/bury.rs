@@ -65,6 +65,8 @@ pub fn handler<'info>(
     ctx: Context<'_, '_, '_, 'info, Bury<'info>>,
     swap_data: Vec<u8>,
 ) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_BURY)?;
+
     // Validate authority
     require!(
         ctx.accounts.signer.key() == ctx.accounts.config.bury_authority,
@@ -211,7 +213,8 @@ pub fn handler<'info>(
 
     msg!("Total TOKEN acquired from swap: {}", total_ore_acquired);
 
-    // Since staking is removed, burn 100% of acquired TOKEN
+    // Stakers take their share of buyback revenue in SOL at reset (stake_bps), before
+    // it reaches bury, so burn 100% of acquired TOKEN
     let burn_amount = total_ore_acquired;
 
     msg!("Burning {} TOKEN tokens...", burn_amount);
@@ -242,14 +245,15 @@ pub fn handler<'info>(
     // Emit BuryEvent for indexing
     emit!(BuryEvent {
         token_burned: burn_amount,
-        token_shared: 0, // No staking in Anchor version
+        token_shared: 0, // Staking share is taken in SOL at reset
         sol_amount: pre_swap_sol_balance,
         new_circulating_supply,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
-    // Note: In Steel version, 10% was shared with stakers
-    // Since staking is removed, we burn 100% for maximum deflation
+    // Note: In Steel version, 10% of the bought TOKEN was shared with stakers. Here
+    // the fee schedule moves the stakers' share out of the buyback split instead, so
+    // they are paid in SOL through stake_rewards_factor and need no TOKEN reward path
 
     Ok(())
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::AppError;
+use crate::events::AutomationClosedEvent;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -8,11 +9,16 @@ pub struct CancelAutomate<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [AUTOMATION, authority.key().as_ref()],
         bump,
-        close = authority, // Auto-close account and send rent to authority
     )]
     pub automation: Account<'info, Automation>,
 
@@ -20,35 +26,42 @@ pub struct CancelAutomate<'info> {
 }
 
 pub fn handler(ctx: Context<CancelAutomate>) -> Result<()> {
-    let automation = &ctx.accounts.automation;
+    ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
 
-    // Verify authority
+    msg!("=== Cancelling Automation ===");
+    close_automation(
+        &mut ctx.accounts.automation,
+        &ctx.accounts.authority.to_account_info(),
+    )
+}
+
+/// Close an automation account, refunding balance and rent to the authority.
+/// Shared by `cancel_automate` and the `automate` close path.
+pub fn close_automation<'info>(
+    automation: &mut Account<'info, Automation>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
     require!(
-        automation.authority == ctx.accounts.authority.key(),
+        automation.authority == authority.key(),
         AppError::NotAuthorized
     );
 
-    // Get current balance before close
-    let balance = automation.balance;
-    let rent_lamports = ctx.accounts.automation.to_account_info().lamports();
+    let total_lamports = automation.to_account_info().lamports();
+    let balance_refunded = automation.balance.min(total_lamports);
+    let rent_refunded = total_lamports - balance_refunded;
+
+    automation.balance = 0;
+    automation.close(authority.clone())?;
+
+    msg!("Automation closed: refunded {} balance + {} rent lamports to {}",
+        balance_refunded, rent_refunded, authority.key());
+
+    emit!(AutomationClosedEvent {
+        authority: authority.key(),
+        balance_refunded,
+        rent_refunded,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
-    msg!("=== Cancelling Automation ===");
-    msg!("Authority: {}", ctx.accounts.authority.key());
-    msg!("Automation balance: {} lamports", balance);
-    msg!("Account rent: {} lamports", rent_lamports);
-    msg!("Total returned: {} lamports", rent_lamports);
-
-    // Transfer automation balance back to authority
-    // (This is the tracked balance, separate from account rent)
-    if balance > 0 {
-        // The balance is just a state field, actual lamports are already in the account
-        // When we close the account, all lamports (including balance) go back to authority
-        msg!("Balance {} will be returned to authority", balance);
-    }
-
-    msg!("✓ Automation account closed successfully");
-    msg!("✓ All lamports returned to authority");
-
-    // Account will be auto-closed by Anchor (close = authority constraint)
     Ok(())
 }
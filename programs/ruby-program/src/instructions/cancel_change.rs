@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ConfigChangeCancelledEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelChange<'info> {
+    /// Admin or guardian
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PENDING_CONFIG_CHANGE, &[pending_change.field as u8]],
+        bump,
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+}
+
+pub fn handler(ctx: Context<CancelChange>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == config.admin || authority == config.guardian,
+        AppError::NotAuthorized
+    );
+
+    let pending_change = &ctx.accounts.pending_change;
+
+    emit!(ConfigChangeCancelledEvent {
+        field: pending_change.field,
+        new_value: pending_change.new_value,
+        authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Config change cancelled: {:?}", pending_change.field);
+
+    // Pending change account will be closed automatically due to close constraint
+    Ok(())
+}
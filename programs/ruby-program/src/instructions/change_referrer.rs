@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ReferrerChangedEvent;
+use crate::state::*;
+use super::checkpoint::load_referral;
+
+#[derive(Accounts)]
+pub struct ChangeReferrer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL, authority.key().as_ref()],
+        bump,
+    )]
+    pub referral: Account<'info, Referral>,
+
+    #[account(
+        seeds = [REFERRAL_CONFIG],
+        bump,
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    /// Stats of the current referrer
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS, referral.referrer.as_ref()],
+        bump,
+    )]
+    pub old_referrer_stats: Account<'info, ReferrerStats>,
+
+    /// CHECK: The new referrer - can be any wallet
+    pub new_referrer: AccountInfo<'info>,
+
+    /// Stats of the new referrer (auto-created if needed)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ReferrerStats::LEN,
+        seeds = [REFERRER_STATS, new_referrer.key().as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    /// CHECK: The new referrer's own referral, to set the second-level referrer.
+    /// Deserialized in the handler (may not exist)
+    #[account(
+        seeds = [REFERRAL, new_referrer.key().as_ref()],
+        bump,
+    )]
+    pub referrer_referral: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for change_referrer instruction
+///
+/// A referee may change referrer once, within the change window after
+/// registering, and only while no rewards are pending on the referral itself.
+/// Rewards already credited to the old referrer's stats stay with them.
+pub fn handler(ctx: Context<ChangeReferrer>) -> Result<()> {
+    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.key();
+    let new_referrer = ctx.accounts.new_referrer.key();
+    let referral = &mut ctx.accounts.referral;
+
+    require!(
+        !referral.referrer_changed
+            && clock.unix_timestamp
+                <= referral.created_at.saturating_add(ctx.accounts.referral_config.change_window),
+        AppError::ReferralChangeNotAllowed
+    );
+    require!(authority != new_referrer, AppError::SelfReferral);
+    require!(referral.referrer != new_referrer, AppError::InvalidReferral);
+    require!(
+        referral.pending_rewards == 0 && referral.second_pending_rewards == 0,
+        AppError::ReferralHasPendingRewards
+    );
+
+    let old_referrer = referral.referrer;
+    referral.referrer = new_referrer;
+    let referrer_referral = load_referral(&ctx.accounts.referrer_referral.to_account_info())?;
+    referral.second_referrer = second_referrer_of(authority, referrer_referral.as_ref());
+    referral.referrer_changed = true;
+
+    let old_referrer_stats = &mut ctx.accounts.old_referrer_stats;
+    old_referrer_stats.referee_count = old_referrer_stats.referee_count.saturating_sub(1);
+
+    let referrer_stats = &mut ctx.accounts.referrer_stats;
+    if referrer_stats.referrer == Pubkey::default() {
+        referrer_stats.referrer = new_referrer;
+    }
+    referrer_stats.referee_count += 1;
+
+    emit!(ReferrerChangedEvent {
+        authority,
+        old_referrer,
+        new_referrer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Referrer changed: {} -> {}", old_referrer, new_referrer);
+
+    Ok(())
+}
+
+/// The second-level referrer for a referee: the referrer's own referrer,
+/// unless that would pay the referee themselves.
+pub fn second_referrer_of(authority: Pubkey, referrer_referral: Option<&Referral>) -> Pubkey {
+    match referrer_referral {
+        Some(r) if r.referrer != authority => r.referrer,
+        _ => Pubkey::default(),
+    }
+}
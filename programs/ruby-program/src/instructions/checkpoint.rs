@@ -1,6 +1,6 @@
 use crate::constants::*;
 use crate::errors::AppError;
-use crate::events::{CheckpointEvent, ReferralRewardAccruedEvent};
+use crate::events::{CheckpointEvent, ReferralRewardAccruedEvent, ReferralTierChangedEvent};
 use crate::state::*;
 use crate::utils::transfer_lamports;
 use anchor_lang::prelude::*;
@@ -11,6 +11,12 @@ pub struct Checkpoint<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         seeds = [BOARD],
         bump,
@@ -24,12 +30,13 @@ pub struct Checkpoint<'info> {
     )]
     pub miner: Account<'info, Miner>,
 
+    /// CHECK: Round PDA, deserialized in the handler (may already be closed by close_round)
     #[account(
         mut,
         seeds = [ROUND, &miner.round_id.to_le_bytes()],
         bump,
     )]
-    pub round: Account<'info, Round>,
+    pub round: UncheckedAccount<'info>,
 
     #[account(
         mut,
@@ -38,52 +45,221 @@ pub struct Checkpoint<'info> {
     )]
     pub treasury: Account<'info, Treasury>,
 
-    /// Referral account - auto-resolved from miner.authority
+    /// CHECK: Referral PDA, deserialized in the handler (may not exist)
     #[account(
         mut,
         seeds = [REFERRAL, miner.authority.as_ref()],
         bump,
     )]
-    pub referral: Account<'info, Referral>,
+    pub referral: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [REFERRAL_CONFIG],
+        bump,
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    /// Stats of the miner's referrer, or of the protocol sink without a referral
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS, referrer_stats.referrer.as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    /// Stats of the second-level referrer (required when the referral has one)
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS, second_referrer_stats.referrer.as_ref()],
+        bump,
+    )]
+    pub second_referrer_stats: Option<Account<'info, ReferrerStats>>,
 
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<Checkpoint>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_CHECKPOINT)?;
+
     let clock = Clock::get()?;
 
     // Clone account infos FIRST before any mutable borrows
     let miner_info = ctx.accounts.miner.to_account_info();
     let round_info = ctx.accounts.round.to_account_info();
+    let referral_info = ctx.accounts.referral.to_account_info();
+    let mut referral = load_referral(&referral_info)?;
+
+    // If round account was closed after expiry, the miner forfeits rewards
+    let mut round_state = if round_info.data_is_empty() {
+        None
+    } else {
+        require_keys_eq!(*round_info.owner, crate::ID, AppError::InvalidRound);
+        Some(Round::try_deserialize(&mut &round_info.try_borrow_data()?[..])?)
+    };
 
     let miner = &mut ctx.accounts.miner;
-    let round = &mut ctx.accounts.round;
-    let treasury = &mut ctx.accounts.treasury;
-    let board = &ctx.accounts.board;
+    let outcome = checkpoint_miner(
+        miner,
+        &miner_info,
+        round_state.as_mut(),
+        &round_info,
+        &ctx.accounts.board,
+        &mut ctx.accounts.treasury,
+        referral.as_mut(),
+        &ctx.accounts.referral_config,
+        &mut ctx.accounts.referrer_stats,
+        ctx.accounts.second_referrer_stats.as_deref_mut(),
+        &clock,
+    )?;
+
+    if outcome.status != CheckpointStatus::Checkpointed {
+        return Ok(());
+    }
+    let round = round_state.as_ref().unwrap();
+
+    // Persist round and referral changes
+    round.try_serialize(&mut *round_info.try_borrow_mut_data()?)?;
+    store_referral(referral.as_ref(), &referral_info)?;
+
+    // Transfer bot fee
+    if outcome.bot_fee > 0 {
+        **ctx.accounts.signer.to_account_info().try_borrow_mut_lamports()? += outcome.bot_fee;
+    }
+
+    emit_checkpoint_event(miner, round, &outcome, &clock);
+
+    Ok(())
+}
+
+/// Load a miner's referral, or None if the miner never registered one
+pub fn load_referral(referral_info: &AccountInfo) -> Result<Option<Referral>> {
+    if referral_info.data_is_empty() {
+        return Ok(None);
+    }
+    require_keys_eq!(*referral_info.owner, crate::ID, AppError::InvalidReferral);
+    Ok(Some(Referral::try_deserialize(&mut &referral_info.try_borrow_data()?[..])?))
+}
+
+/// Write back a referral loaded with `load_referral`
+pub fn store_referral(referral: Option<&Referral>, referral_info: &AccountInfo) -> Result<()> {
+    if let Some(referral) = referral {
+        referral.try_serialize(&mut *referral_info.try_borrow_mut_data()?)?;
+    }
+    Ok(())
+}
+
+/// Emit CheckpointEvent for a completed checkpoint
+pub fn emit_checkpoint_event(miner: &Miner, round: &Round, outcome: &CheckpointOutcome, clock: &Clock) {
+    emit!(CheckpointEvent {
+        miner: miner.authority,
+        round_id: round.id,
+        winning_square: if let Some(r) = round.rng() {
+            round.winning_square(r) as u64
+        } else {
+            u64::MAX
+        },
+        rewards_sol: outcome.rewards_sol,
+        rewards_token: outcome.rewards_token,
+        bot_fee: outcome.bot_fee,
+        is_top_miner: round.top_miner == miner.authority,
+        timestamp: clock.unix_timestamp,
+    });
+}
+
+/// Rewards credited by one checkpoint
+pub struct CheckpointOutcome {
+    pub status: CheckpointStatus,
+    pub rewards_sol: u64,
+    pub rewards_token: u64,
+    pub referral_fee: u64,
+    pub bot_fee: u64,
+}
+
+impl CheckpointOutcome {
+    fn skipped(status: CheckpointStatus) -> Self {
+        CheckpointOutcome {
+            status,
+            rewards_sol: 0,
+            rewards_token: 0,
+            referral_fee: 0,
+            bot_fee: 0,
+        }
+    }
+}
+
+/// Checkpoint one miner against its round. `round` is None when the round account
+/// was closed, and `referral` is None when the miner never registered one.
+/// `second_referrer_stats` is required when the referral has a second-level referrer.
+/// Round changes (top miner) are left for the caller to persist, and the
+/// bot fee is debited from the miner for the caller to credit to the signer.
+#[allow(clippy::too_many_arguments)]
+pub fn checkpoint_miner<'info>(
+    miner: &mut Miner,
+    miner_info: &AccountInfo<'info>,
+    round: Option<&mut Round>,
+    round_info: &AccountInfo<'info>,
+    board: &Board,
+    treasury: &mut Treasury,
+    referral: Option<&mut Referral>,
+    referral_config: &ReferralConfig,
+    referrer_stats: &mut ReferrerStats,
+    second_referrer_stats: Option<&mut ReferrerStats>,
+    clock: &Clock,
+) -> Result<CheckpointOutcome> {
+    // Referral fees go to the referrer, or to the protocol sink without a referral
+    let referrer = referral
+        .as_ref()
+        .map_or(referral_config.protocol_referrer, |r| r.referrer);
+    require_keys_eq!(referrer_stats.referrer, referrer, AppError::InvalidReferral);
+
+    // Second-level fees go to the second referrer's stats
+    let second_referrer = referral
+        .as_ref()
+        .map_or(Pubkey::default(), |r| r.second_referrer);
+    let second_referrer_stats = if second_referrer == Pubkey::default() {
+        None
+    } else {
+        let stats = second_referrer_stats.ok_or(AppError::InvalidReferral)?;
+        require_keys_eq!(stats.referrer, second_referrer, AppError::InvalidReferral);
+        Some(stats)
+    };
 
     msg!("=== Checkpoint Handler ===");
-    msg!("Miner: {}, Round: {}, Slot: {}", miner.authority, round.id, clock.slot);
+    msg!("Miner: {}, Round: {}, Slot: {}", miner.authority, miner.round_id, clock.slot);
     msg!("Miner checkpoint_id: {}, round_id: {}", miner.checkpoint_id, miner.round_id);
 
     // If miner has already checkpointed this round, return
     if miner.checkpoint_id == miner.round_id {
         msg!("Checkpoint skipped: Miner {} already checkpointed round {} (checkpoint_id: {})",
             miner.authority, miner.round_id, miner.checkpoint_id);
-        return Ok(());
+        return Ok(CheckpointOutcome::skipped(CheckpointStatus::AlreadyCheckpointed));
     }
 
+    // If round account was closed after expiry, the miner forfeited rewards
+    let Some(round) = round else {
+        if !miner.can_forfeit(miner.round_id, board.round_id) {
+            msg!("Checkpoint skipped: Round {} has no account and has not ended (current board round: {})",
+                miner.round_id, board.round_id);
+            return Ok(CheckpointOutcome::skipped(CheckpointStatus::RoundActive));
+        }
+        miner.checkpoint_id = miner.round_id;
+        msg!("Checkpoint expired: Round {} was closed. Miner {} forfeited rewards.",
+            miner.round_id, miner.authority);
+        return Ok(CheckpointOutcome::skipped(CheckpointStatus::Forfeited));
+    };
+
     // If round is current round, return
     if round.id == board.round_id {
         msg!("Checkpoint skipped: Round {} is still active (current board round: {})",
             round.id, board.round_id);
-        return Ok(());
+        return Ok(CheckpointOutcome::skipped(CheckpointStatus::RoundActive));
     }
 
     // If miner round ID does not match, return
     if round.id != miner.round_id {
         msg!("Checkpoint skipped: Round mismatch - round.id: {}, miner.round_id: {}",
             round.id, miner.round_id);
-        return Ok(());
+        return Ok(CheckpointOutcome::skipped(CheckpointStatus::RoundMismatch));
     }
 
     // If round not finalized (slot_hash = 0), just update checkpoint_id and return
@@ -91,7 +267,7 @@ pub fn handler(ctx: Context<Checkpoint>) -> Result<()> {
         miner.checkpoint_id = miner.round_id;
         msg!("Checkpoint partial: Round {} not finalized (no slot_hash), updating checkpoint_id only",
             round.id);
-        return Ok(());
+        return Ok(CheckpointOutcome::skipped(CheckpointStatus::NotFinalized));
     }
 
     // Ensure round is not expired
@@ -99,7 +275,7 @@ pub fn handler(ctx: Context<Checkpoint>) -> Result<()> {
         miner.checkpoint_id = miner.round_id;
         msg!("Checkpoint expired: Round {} expired at slot {} (current: {}). Miner {} forfeited rewards.",
             round.id, round.expires_at, clock.slot, miner.authority);
-        return Ok(());
+        return Ok(CheckpointOutcome::skipped(CheckpointStatus::Forfeited));
     }
 
     // Calculate bot fee
@@ -143,30 +319,50 @@ pub fn handler(ctx: Context<Checkpoint>) -> Result<()> {
                     miner.authority, rewards_token, miner.deployed[winning_square], winning_square);
             } else {
                 let top_miner_sample = round.top_miner_sample(r, winning_square);
-                if top_miner_sample >= miner.cumulative[winning_square]
-                    && top_miner_sample
-                        < miner.cumulative[winning_square] + miner.deployed[winning_square]
-                {
+                if miner.owns_ticket(winning_square, top_miner_sample) {
                     rewards_token = round.top_miner_reward;
                     round.top_miner = miner.authority;
-                    msg!("Top miner: Miner {} wins {} TOKEN! (sample: {}, range: [{}, {}), square: {})",
+                    msg!("Top miner: Miner {} wins {} TOKEN! (sample: {}, range: [{}, {}), top-up: [{}, {}), square: {})",
                         miner.authority, rewards_token, top_miner_sample,
                         miner.cumulative[winning_square],
-                        miner.cumulative[winning_square] + miner.deployed[winning_square],
+                        miner.cumulative[winning_square] + miner.deployed[winning_square]
+                            - miner.top_up_deployed[winning_square],
+                        miner.top_up_cumulative[winning_square],
+                        miner.top_up_cumulative[winning_square] + miner.top_up_deployed[winning_square],
                         winning_square);
                 }
             }
 
-            // Calculate motherlode rewards
+            // Calculate motherlode rewards (RUBY)
             if round.motherlode > 0 {
                 let motherlode_rewards =
                     ((round.motherlode as u128 * miner.deployed[winning_square] as u128)
                         / round.deployed[winning_square] as u128) as u64;
-                rewards_sol += motherlode_rewards;
-                msg!("Motherlode hit! Miner {} receives {} SOL (proportional share of {} total, ratio: {}/{})",
+                rewards_token += motherlode_rewards;
+                msg!("Motherlode hit! Miner {} receives {} TOKEN (proportional share of {} total, ratio: {}/{})",
                     miner.authority, motherlode_rewards, round.motherlode,
                     miner.deployed[winning_square], round.deployed[winning_square]);
             }
+
+            // Calculate mini-motherlode rewards (SOL)
+            if round.mini_motherlode_sol > 0 {
+                let mini_motherlode_rewards =
+                    ((round.mini_motherlode_sol as u128 * miner.deployed[winning_square] as u128)
+                        / round.deployed[winning_square] as u128) as u64;
+                rewards_sol += mini_motherlode_rewards;
+                msg!("Mini-Motherlode hit! Miner {} receives {} SOL (proportional share of {} total)",
+                    miner.authority, mini_motherlode_rewards, round.mini_motherlode_sol);
+            }
+
+            // Calculate SOL motherlode rewards
+            if round.motherlode_sol > 0 {
+                let motherlode_sol_rewards =
+                    ((round.motherlode_sol as u128 * miner.deployed[winning_square] as u128)
+                        / round.deployed[winning_square] as u128) as u64;
+                rewards_sol += motherlode_sol_rewards;
+                msg!("SOL Motherlode hit! Miner {} receives {} SOL (proportional share of {} total)",
+                    miner.authority, motherlode_sol_rewards, round.motherlode_sol);
+            }
         }
     } else {
         // Round has no slot hash, refund all SOL
@@ -179,24 +375,63 @@ pub fn handler(ctx: Context<Checkpoint>) -> Result<()> {
     // Update rewards
     miner.update_rewards(treasury);
 
-    // Calculate referral fee (1% of TOKEN rewards)
-    let referral = &mut ctx.accounts.referral;
+    // Credit the referee's deployed SOL to the referrer's volume and update its tier
+    // (the protocol sink stays at the base rate)
+    if referral.is_some() {
+        let volume: u64 = miner.deployed.iter().sum();
+        referrer_stats.referred_volume = referrer_stats.referred_volume.saturating_add(volume);
+        let new_tier = referral_config.tier_for(referrer_stats.referred_volume);
+        if new_tier != referrer_stats.tier {
+            emit!(ReferralTierChangedEvent {
+                referrer: referrer_stats.referrer,
+                old_tier: referrer_stats.tier,
+                new_tier,
+                referred_volume: referrer_stats.referred_volume,
+                timestamp: clock.unix_timestamp,
+            });
+            referrer_stats.tier = new_tier;
+        }
+    }
+
+    // Track the round's credited TOKEN so close_round can burn the rest
+    round.total_token_credited = round.total_token_credited.saturating_add(rewards_token);
+
+    // Calculate referral fees (tiered referrer rate + second-level share of TOKEN rewards)
     let mut referral_fee = 0u64;
     if rewards_token > 0 {
-        referral_fee = rewards_token / 100; // 1%
-        referral.pending_rewards = referral.pending_rewards
-            .checked_add(referral_fee)
+        let tier = if referral.is_some() { referrer_stats.tier } else { 0 };
+        let first_fee = FeeSchedule::share(rewards_token, referral_config.bps_for(tier));
+        referrer_stats.pending_rewards = referrer_stats.pending_rewards
+            .checked_add(first_fee)
             .ok_or(AppError::Overflow)?;
-        rewards_token = rewards_token.saturating_sub(referral_fee);
-        msg!("Referral fee: {} TOKEN (1%) -> referrer: {}", referral_fee, referral.referrer);
+        msg!("Referral fee: {} TOKEN (tier {}) -> referrer: {}",
+            first_fee, tier, referrer_stats.referrer);
 
-        // Emit referral accrued event
         emit!(ReferralRewardAccruedEvent {
             referee: miner.authority,
-            referrer: referral.referrer,
-            amount: referral_fee,
+            referrer: referrer_stats.referrer,
+            amount: first_fee,
             timestamp: clock.unix_timestamp,
         });
+
+        let mut second_fee = 0;
+        if let Some(second_referrer_stats) = second_referrer_stats {
+            second_fee = FeeSchedule::share(rewards_token, referral_config.second_level_bps);
+            second_referrer_stats.pending_rewards = second_referrer_stats.pending_rewards
+                .checked_add(second_fee)
+                .ok_or(AppError::Overflow)?;
+            msg!("Second-level referral fee: {} TOKEN -> {}", second_fee, second_referrer);
+
+            emit!(ReferralRewardAccruedEvent {
+                referee: miner.authority,
+                referrer: second_referrer,
+                amount: second_fee,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        referral_fee = first_fee + second_fee;
+        rewards_token = rewards_token.saturating_sub(referral_fee);
     }
 
     // Checkpoint miner
@@ -231,7 +466,7 @@ pub fn handler(ctx: Context<Checkpoint>) -> Result<()> {
         }
 
         if actual_rewards > 0 {
-            transfer_lamports(&round_info, &miner_info, actual_rewards)?;
+            transfer_lamports(round_info, miner_info, actual_rewards)?;
             actual_rewards_transferred = actual_rewards;
 
             // Update miner's actual rewards (not full calculated amount)
@@ -240,9 +475,9 @@ pub fn handler(ctx: Context<Checkpoint>) -> Result<()> {
         }
     }
 
-    // Transfer bot fee
+    // Debit bot fee (credited to the signer by the caller)
     if bot_fee > 0 {
-        transfer_lamports(&miner_info, &ctx.accounts.signer.to_account_info(), bot_fee)?;
+        **miner_info.try_borrow_mut_lamports()? -= bot_fee;
     }
 
     // Verify rent exemption after all transfers (use actual transferred amount)
@@ -254,21 +489,11 @@ pub fn handler(ctx: Context<Checkpoint>) -> Result<()> {
     msg!("✓ Checkpoint complete: Miner {} earned {} SOL + {} TOKEN (referral_fee: {}, bot_fee: {})",
         miner.authority, rewards_sol, rewards_token, referral_fee, bot_fee);
 
-    // Emit checkpoint event
-    emit!(CheckpointEvent {
-        miner: miner.authority,
-        round_id: round.id,
-        winning_square: if let Some(r) = round.rng() {
-            round.winning_square(r) as u64
-        } else {
-            u64::MAX
-        },
+    Ok(CheckpointOutcome {
+        status: CheckpointStatus::Checkpointed,
         rewards_sol,
         rewards_token,
+        referral_fee,
         bot_fee,
-        is_top_miner: round.top_miner == miner.authority,
-        timestamp: clock.unix_timestamp,
-    });
-
-    Ok(())
+    })
 }
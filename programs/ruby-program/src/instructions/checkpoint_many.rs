@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::CheckpointManyEvent;
+use crate::state::*;
+use super::checkpoint::{checkpoint_miner, load_referral, store_referral};
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct CheckpointMany<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [BOARD],
+        bump,
+    )]
+    pub board: Account<'info, Board>,
+
+    /// CHECK: Round PDA, deserialized in the handler (may already be closed by close_round)
+    #[account(
+        mut,
+        seeds = [ROUND, &round_id.to_le_bytes()],
+        bump,
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [REFERRAL_CONFIG],
+        bump,
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for checkpoint_many instruction
+///
+/// remaining_accounts:
+/// - (miner, referral, referrer_stats, second_referrer_stats) groups, all writable, for
+///   miners that played `round_id`. The referral PDA may be uninitialized; its
+///   referrer_stats is then the protocol sink's. second_referrer_stats is ignored when
+///   the referral has no second-level referrer.
+///
+/// Permissionless crank. Runs the same checkpoint as `checkpoint` for each miner,
+/// pays the accumulated bot fees to the signer in one transfer, and reports each
+/// miner's outcome in CheckpointManyEvent.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CheckpointMany<'info>>,
+    round_id: u64,
+) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_CHECKPOINT)?;
+
+    let clock = Clock::get()?;
+    let round_info = ctx.accounts.round.to_account_info();
+
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 4 == 0,
+        AppError::InvalidRemainingAccounts
+    );
+
+    // If round account was closed after expiry, miners of that round forfeit rewards
+    let mut round_state = if round_info.data_is_empty() {
+        None
+    } else {
+        require_keys_eq!(*round_info.owner, crate::ID, AppError::InvalidRound);
+        Some(Round::try_deserialize(&mut &round_info.try_borrow_data()?[..])?)
+    };
+
+    let mut results = Vec::with_capacity(ctx.remaining_accounts.len() / 4);
+    let mut total_bot_fee = 0u64;
+
+    for accounts in ctx.remaining_accounts.chunks(4) {
+        let miner_info = &accounts[0];
+        let referral_info = &accounts[1];
+        let stats_info = &accounts[2];
+        let second_stats_info = &accounts[3];
+
+        // Validate miner PDA
+        require!(miner_info.is_writable, AppError::NotAuthorized);
+        require_keys_eq!(*miner_info.owner, crate::ID, AppError::NotAuthorized);
+        let mut miner = Miner::try_deserialize(&mut &miner_info.try_borrow_data()?[..])?;
+        let (miner_pda, _) =
+            Pubkey::find_program_address(&[MINER, miner.authority.as_ref()], &crate::ID);
+        require_keys_eq!(miner_info.key(), miner_pda, AppError::NotAuthorized);
+
+        // Only miners whose last round is `round_id` can be checkpointed against it
+        if miner.round_id != round_id {
+            msg!("Checkpoint skipped: Round mismatch - round_id: {}, miner.round_id: {}",
+                round_id, miner.round_id);
+            results.push(CheckpointResult {
+                miner: miner.authority,
+                status: CheckpointStatus::RoundMismatch,
+                rewards_sol: 0,
+                rewards_token: 0,
+                bot_fee: 0,
+            });
+            continue;
+        }
+
+        // Validate referral PDA (may not exist)
+        require!(referral_info.is_writable, AppError::InvalidReferral);
+        let (referral_pda, _) =
+            Pubkey::find_program_address(&[REFERRAL, miner.authority.as_ref()], &crate::ID);
+        require_keys_eq!(referral_info.key(), referral_pda, AppError::InvalidReferral);
+        let mut referral = load_referral(referral_info)?;
+
+        // Validate referrer stats PDA (checkpoint_miner checks it belongs to the referrer)
+        require!(stats_info.is_writable, AppError::InvalidReferral);
+        require_keys_eq!(*stats_info.owner, crate::ID, AppError::InvalidReferral);
+        let mut referrer_stats =
+            ReferrerStats::try_deserialize(&mut &stats_info.try_borrow_data()?[..])?;
+        let (stats_pda, _) = Pubkey::find_program_address(
+            &[REFERRER_STATS, referrer_stats.referrer.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(stats_info.key(), stats_pda, AppError::InvalidReferral);
+
+        // Second-level referrer stats, only read when the referral has a second referrer
+        let mut second_referrer_stats = match referral.as_ref() {
+            Some(r) if r.second_referrer != Pubkey::default() => {
+                require!(second_stats_info.is_writable, AppError::InvalidReferral);
+                require_keys_eq!(*second_stats_info.owner, crate::ID, AppError::InvalidReferral);
+                let (second_stats_pda, _) = Pubkey::find_program_address(
+                    &[REFERRER_STATS, r.second_referrer.as_ref()],
+                    &crate::ID,
+                );
+                require_keys_eq!(second_stats_info.key(), second_stats_pda, AppError::InvalidReferral);
+                Some(ReferrerStats::try_deserialize(&mut &second_stats_info.try_borrow_data()?[..])?)
+            }
+            _ => None,
+        };
+
+        let outcome = checkpoint_miner(
+            &mut miner,
+            miner_info,
+            round_state.as_mut(),
+            &round_info,
+            &ctx.accounts.board,
+            &mut ctx.accounts.treasury,
+            referral.as_mut(),
+            &ctx.accounts.referral_config,
+            &mut referrer_stats,
+            second_referrer_stats.as_mut(),
+            &clock,
+        )?;
+
+        // Serialize back
+        miner.try_serialize(&mut *miner_info.try_borrow_mut_data()?)?;
+        store_referral(referral.as_ref(), referral_info)?;
+        referrer_stats.try_serialize(&mut *stats_info.try_borrow_mut_data()?)?;
+        if let Some(second_referrer_stats) = second_referrer_stats.as_ref() {
+            second_referrer_stats.try_serialize(&mut *second_stats_info.try_borrow_mut_data()?)?;
+        }
+
+        total_bot_fee = total_bot_fee
+            .checked_add(outcome.bot_fee)
+            .ok_or(AppError::Overflow)?;
+
+        results.push(CheckpointResult {
+            miner: miner.authority,
+            status: outcome.status,
+            rewards_sol: outcome.rewards_sol,
+            rewards_token: outcome.rewards_token,
+            bot_fee: outcome.bot_fee,
+        });
+    }
+
+    // Persist round changes (top miner)
+    if let Some(round) = round_state.as_ref() {
+        round.try_serialize(&mut *round_info.try_borrow_mut_data()?)?;
+    }
+
+    // Transfer accumulated bot fees (already debited from each miner)
+    if total_bot_fee > 0 {
+        **ctx.accounts.signer.to_account_info().try_borrow_mut_lamports()? += total_bot_fee;
+    }
+
+    msg!("Checkpointed {} miners for round {} (bot fees: {})",
+        results.len(), round_id, total_bot_fee);
+
+    emit!(CheckpointManyEvent {
+        round_id,
+        signer: ctx.accounts.signer.key(),
+        total_bot_fee,
+        results,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
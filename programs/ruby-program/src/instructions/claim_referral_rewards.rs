@@ -11,6 +11,12 @@ pub struct ClaimReferralRewards<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [TREASURY],
@@ -46,10 +52,12 @@ pub struct ClaimReferralRewards<'info> {
 /// Handler for claim_referral_rewards instruction
 ///
 /// remaining_accounts:
-/// - List of Referral PDAs to claim from (all must have referrer == authority)
+/// - List of Referral PDAs to claim from (each must have referrer or second_referrer == authority)
 ///
 /// Referrer (authority) claims pending rewards from multiple referees in one transaction
 pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimReferralRewards<'info>>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_CLAIM)?;
+
     let clock = Clock::get()?;
     let authority = ctx.accounts.authority.key();
 
@@ -72,17 +80,28 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimReferralRewards<'i
         let mut referral_data = referral_info.try_borrow_mut_data()?;
         let mut referral: Referral = Referral::try_deserialize(&mut &referral_data[..])?;
 
-        // Verify this referral belongs to the authority (referrer)
+        // Verify this referral pays the authority (referrer or second-level referrer)
+        let is_referrer = referral.referrer == authority;
+        let is_second_referrer = referral.second_referrer == authority;
         require!(
-            referral.referrer == authority,
+            is_referrer || is_second_referrer,
             AppError::InvalidReferral
         );
 
         // Get pending rewards
-        let pending = referral.pending_rewards;
+        let mut pending = 0u64;
+        if is_referrer {
+            pending += referral.pending_rewards;
+            referral.pending_rewards = 0;
+        }
+        if is_second_referrer {
+            pending = pending
+                .checked_add(referral.second_pending_rewards)
+                .ok_or(AppError::Overflow)?;
+            referral.second_pending_rewards = 0;
+        }
         if pending > 0 {
             // Update referral account
-            referral.pending_rewards = 0;
             referral.claimed_rewards = referral.claimed_rewards
                 .checked_add(pending)
                 .ok_or(AppError::Overflow)?;
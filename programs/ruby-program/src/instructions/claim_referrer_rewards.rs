@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ReferralRewardClaimedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ClaimReferrerRewards<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS, authority.key().as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(address = MINT_ADDRESS)]
+    pub mint: Account<'info, Mint>,
+
+    /// Treasury's token account (source of tokens)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_tokens: Account<'info, TokenAccount>,
+
+    /// Referrer's token account (auto-created if needed)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = authority
+    )]
+    pub recipient: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for claim_referrer_rewards instruction
+///
+/// Referrer (authority) claims all rewards credited to its stats account,
+/// regardless of how many referees it has.
+pub fn handler(ctx: Context<ClaimReferrerRewards>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_CLAIM)?;
+
+    let clock = Clock::get()?;
+    let referrer_stats = &mut ctx.accounts.referrer_stats;
+
+    let amount = referrer_stats.pending_rewards;
+    require!(amount > 0, AppError::InvalidAmount);
+
+    referrer_stats.pending_rewards = 0;
+    referrer_stats.claimed_rewards = referrer_stats.claimed_rewards
+        .checked_add(amount)
+        .ok_or(AppError::Overflow)?;
+
+    // Transfer claimed tokens to referrer
+    let treasury_bump = ctx.bumps.treasury;
+    let signer_seeds: &[&[&[u8]]] = &[&[TREASURY, &[treasury_bump]]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury_tokens.to_account_info(),
+        to: ctx.accounts.recipient.to_account_info(),
+        authority: ctx.accounts.treasury.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    emit!(ReferralRewardClaimedEvent {
+        referrer: ctx.accounts.authority.key(),
+        amount,
+        num_referees: referrer_stats.referee_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Claimed {} TOKEN from {} referees", amount, referrer_stats.referee_count);
+
+    Ok(())
+}
@@ -8,6 +8,12 @@ pub struct ClaimSol<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [MINER, authority.key().as_ref()],
@@ -19,6 +25,8 @@ pub struct ClaimSol<'info> {
 }
 
 pub fn handler(ctx: Context<ClaimSol>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
+
     let clock = Clock::get()?;
     let miner = &mut ctx.accounts.miner;
 
@@ -9,6 +9,12 @@ pub struct ClaimToken<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [MINER, authority.key().as_ref()],
@@ -56,6 +62,8 @@ pub struct ClaimToken<'info> {
 /// Referral fee is calculated and accrued at checkpoint instruction.
 /// Claim token transfers the full rewards_token to user (already minus 1% referral fee).
 pub fn handler(ctx: Context<ClaimToken>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_CLAIM)?;
+
     let clock = Clock::get()?;
     let miner = &mut ctx.accounts.miner;
     let treasury = &mut ctx.accounts.treasury;
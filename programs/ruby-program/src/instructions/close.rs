@@ -7,6 +7,12 @@ pub struct Close<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         close = authority,
@@ -16,7 +22,9 @@ pub struct Close<'info> {
     pub miner: Account<'info, Miner>,
 }
 
-pub fn handler(_ctx: Context<Close>) -> Result<()> {
+pub fn handler(ctx: Context<Close>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
+
     // Miner account will be closed automatically due to close constraint
     // All lamports will be transferred to authority
     Ok(())
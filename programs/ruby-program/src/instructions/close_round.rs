@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::RoundClosedEvent;
+use crate::state::*;
+use crate::utils::transfer_lamports;
+
+#[derive(Accounts)]
+pub struct CloseRound<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [BOARD],
+        bump,
+    )]
+    pub board: Account<'info, Board>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer @ AppError::NotAuthorized,
+        seeds = [ROUND, &round.id.to_le_bytes()],
+        bump,
+    )]
+    pub round: Box<Account<'info, Round>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        address = MINT_ADDRESS
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// Treasury token account holding the round's minted TOKEN
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_tokens: Account<'info, TokenAccount>,
+
+    /// CHECK: Validated against round.rent_payer
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Handler for close_round instruction
+///
+/// Permissionless. Once a round has expired, any SOL miners did not checkpoint is
+/// swept into the treasury buyback balance, the TOKEN minted for the round that no
+/// checkpoint credited is burned, and the rent is returned to the rent payer.
+/// That TOKEN was never added to `treasury.total_unclaimed`, so the total is unchanged.
+pub fn handler(ctx: Context<CloseRound>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_RESET)?;
+
+    let clock = Clock::get()?;
+    let round_info = ctx.accounts.round.to_account_info();
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let round = &ctx.accounts.round;
+    let treasury = &mut ctx.accounts.treasury;
+
+    require!(round.id < ctx.accounts.board.round_id, AppError::RoundNotExpired);
+    require!(clock.slot >= round.expires_at, AppError::RoundNotExpired);
+
+    // Sweep unclaimed SOL, leaving only the rent reserve
+    let rent_reserve = Rent::get()?.minimum_balance(round_info.data_len());
+    let unclaimed = round_info.lamports().saturating_sub(rent_reserve);
+    if unclaimed > 0 {
+        transfer_lamports(&round_info, &treasury_info, unclaimed)?;
+        treasury.buyback_bl = treasury.buyback_bl
+            .checked_add(unclaimed)
+            .ok_or(AppError::Overflow)?;
+    }
+
+    // Burn the round's TOKEN that was never credited to a miner
+    let unclaimed_token = round.uncredited_token().min(ctx.accounts.treasury_tokens.amount);
+    if unclaimed_token > 0 {
+        let treasury_bump = ctx.bumps.treasury;
+        let signer_seeds: &[&[&[u8]]] = &[&[TREASURY, &[treasury_bump]]];
+        let burn_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.treasury_tokens.to_account_info(),
+                authority: treasury_info.clone(),
+            },
+            signer_seeds,
+        );
+        token::burn(burn_ctx, unclaimed_token)?;
+    }
+
+    msg!("Closing round {}: swept {} lamports, burned {} TOKEN, returning {} rent to {}",
+        round.id, unclaimed, unclaimed_token, round_info.lamports(), round.rent_payer);
+
+    emit!(RoundClosedEvent {
+        round_id: round.id,
+        unclaimed_swept: unclaimed,
+        unclaimed_token_burned: unclaimed_token,
+        rent_returned: round_info.lamports(),
+        rent_payer: round.rent_payer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Round account will be closed automatically due to close constraint
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use crate::constants::*;
 use crate::errors::AppError;
-use crate::events::DeployEvent;
+use crate::events::{AutomationDepletedEvent, DeployEvent};
 use crate::state::*;
-use crate::utils::{transfer_lamports, transfer_sol_cpi};
+use crate::utils::{transfer_lamports, transfer_lamports_safe, transfer_sol_cpi};
 use anchor_lang::prelude::*;
+use super::checkpoint::{checkpoint_miner, emit_checkpoint_event, load_referral, store_referral};
 use solana_nostd_keccak::hashv;
 use solana_program::program::invoke_signed;
 
@@ -61,19 +62,91 @@ pub struct Deploy<'info> {
     /// CHECK: Entropy program (REQUIRED for VRF)
     pub entropy_program: AccountInfo<'info>,
 
-    /// Referral account - required to deploy
+    /// CHECK: Referral PDA, for automation auto-checkpoint (may not exist)
     #[account(
+        mut,
         seeds = [REFERRAL, authority.key().as_ref()],
         bump,
     )]
-    pub referral: Account<'info, Referral>,
+    pub referral: UncheckedAccount<'info>,
+
+    /// CHECK: The miner's prior round, for automation auto-checkpoint (may be closed)
+    #[account(
+        mut,
+        seeds = [ROUND, &miner.round_id.to_le_bytes()],
+        bump,
+    )]
+    pub previous_round: Option<UncheckedAccount<'info>>,
+
+    /// Registry entry of the signer, for open-market automation
+    #[account(
+        mut,
+        seeds = [EXECUTOR, signer.key().as_ref()],
+        bump,
+    )]
+    pub executor: Option<Box<Account<'info, Executor>>>,
+
+    /// Treasury, for automation auto-checkpoint
+    #[account(
+        mut,
+        seeds = [TREASURY],
+        bump,
+    )]
+    pub treasury: Option<Box<Account<'info, Treasury>>>,
+
+    /// Referral config, for automation auto-checkpoint
+    #[account(
+        seeds = [REFERRAL_CONFIG],
+        bump,
+    )]
+    pub referral_config: Option<Box<Account<'info, ReferralConfig>>>,
+
+    /// Stats of the miner's referrer (or the protocol sink), for automation auto-checkpoint
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS, referrer_stats.referrer.as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Option<Box<Account<'info, ReferrerStats>>>,
+
+    /// Stats of the second-level referrer, for automation auto-checkpoint
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS, second_referrer_stats.referrer.as_ref()],
+        bump,
+    )]
+    pub second_referrer_stats: Option<Box<Account<'info, ReferrerStats>>>,
+
+    /// Weekly leaderboard for the current epoch (auto-created if needed)
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = Leaderboard::LEN,
+        seeds = [LEADERBOARD, &board.epoch(Clock::get()?.slot).to_le_bytes()],
+        bump,
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
 
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()> {
+/// How a deploy spreads SOL across the board
+pub enum DeployAmounts {
+    /// The same amount on every square in the mask (automation overrides both)
+    Uniform { amount: u64, squares: u32 },
+    /// An explicit amount per square (0 to skip)
+    Weighted([u64; TOTAL_BOARD]),
+}
+
+pub fn handler(ctx: Context<Deploy>, amount: u64, squares: u32) -> Result<()> {
+    process_deploy(ctx, DeployAmounts::Uniform { amount, squares })
+}
+
+/// Shared deploy flow for `deploy` and `deploy_weighted`
+pub fn process_deploy(ctx: Context<Deploy>, request: DeployAmounts) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_DEPLOY)?;
+
     msg!("=== Deploy Handler Start ===");
-    msg!("Amount: {}, Squares: {}", amount, squares);
     msg!("Signer: {}", ctx.accounts.signer.key());
     msg!("Authority: {}", ctx.accounts.authority.key());
 
@@ -112,6 +185,9 @@ pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()
             round.total_vaulted = 0;
             round.total_stake_rewards = 0;
             round.total_winnings = 0;
+            round.mini_motherlode_sol = 0;
+            round.motherlode_sol = 0;
+            round.total_token_credited = 0;
         }
 
         board.start_slot = clock.slot;
@@ -193,17 +269,26 @@ pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()
     );
 
     // Check if signer is automation executor
+    let mut executor_fee = 0;
     let is_automation = if let Some(automation) = &ctx.accounts.automation {
         msg!("Automation mode detected");
-        // Validate executor
-        require!(
-            automation.executor == ctx.accounts.signer.key(),
-            AppError::NotAuthorized
-        );
+        // Validate executor: the dedicated executor, or any registered executor
+        // within the authority's max fee in open-market mode (first come per round)
+        if automation.executor == ctx.accounts.signer.key() {
+            executor_fee = automation.fee;
+        } else {
+            let registry = ctx.accounts.executor.as_ref().ok_or(AppError::NotAuthorized)?;
+            require!(
+                automation.open_market && registry.fee <= automation.max_fee,
+                AppError::NotAuthorized
+            );
+            executor_fee = registry.fee;
+        }
         require!(
             automation.authority == ctx.accounts.authority.key(),
             AppError::NotAuthorized
         );
+        require!(automation.is_active(), AppError::AutomationNotActive);
         true
     } else {
         msg!("Manual deploy mode");
@@ -222,6 +307,8 @@ pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()
         miner.checkpoint_id = 0;
         miner.deployed = [0; TOTAL_BOARD];
         miner.cumulative = [0; TOTAL_BOARD];
+        miner.top_up_cumulative = [0; TOTAL_BOARD];
+        miner.top_up_deployed = [0; TOTAL_BOARD];
         miner.rewards_sol = 0;
         miner.rewards_token = 0;
         miner.refined_token = 0;
@@ -230,6 +317,8 @@ pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()
         miner.checkpoint_fee = 0;
         miner.last_claim_token_at = 0;
         miner.last_claim_sol_at = 0;
+        miner.leaderboard_epoch = 0;
+        miner.leaderboard_deployed = 0;
     }
 
     // Validate miner authority
@@ -245,6 +334,79 @@ pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()
         );
     }
 
+    // Automation: checkpoint the prior round inline if configured
+    if miner.round_id != round.id && miner.checkpoint_id != miner.round_id {
+        if let (
+            Some(automation),
+            Some(previous_round),
+            Some(treasury),
+            Some(referral_config),
+            Some(referrer_stats),
+        ) = (
+            ctx.accounts.automation.as_mut(),
+            ctx.accounts.previous_round.as_ref(),
+            ctx.accounts.treasury.as_mut(),
+            ctx.accounts.referral_config.as_ref(),
+            ctx.accounts.referrer_stats.as_mut(),
+        ) {
+            if automation.auto_checkpoint {
+                ctx.accounts.config.assert_not_paused(PAUSE_CHECKPOINT)?;
+
+                let previous_round_info = previous_round.to_account_info();
+                let mut previous_state = if previous_round_info.data_is_empty() {
+                    None
+                } else {
+                    require_keys_eq!(*previous_round_info.owner, crate::ID, AppError::InvalidRound);
+                    Some(Round::try_deserialize(&mut &previous_round_info.try_borrow_data()?[..])?)
+                };
+                let referral_info = ctx.accounts.referral.to_account_info();
+                let mut referral = load_referral(&referral_info)?;
+
+                let outcome = checkpoint_miner(
+                    miner,
+                    &miner_info,
+                    previous_state.as_mut(),
+                    &previous_round_info,
+                    board,
+                    treasury,
+                    referral.as_mut(),
+                    referral_config,
+                    referrer_stats,
+                    ctx.accounts.second_referrer_stats.as_mut().map(|stats| &mut ***stats),
+                    &clock,
+                )?;
+
+                if outcome.status == CheckpointStatus::Checkpointed {
+                    let previous = previous_state.as_ref().unwrap();
+                    previous.try_serialize(&mut *previous_round_info.try_borrow_mut_data()?)?;
+                    store_referral(referral.as_ref(), &referral_info)?;
+
+                    // Bot fee goes to the executor
+                    if outcome.bot_fee > 0 {
+                        **ctx.accounts.signer.to_account_info().try_borrow_mut_lamports()? += outcome.bot_fee;
+                    }
+
+                    emit_checkpoint_event(miner, previous, &outcome, &clock);
+                }
+
+                // Recycle SOL winnings into the automation balance
+                if automation.recycle_winnings && miner.rewards_sol > 0 {
+                    let recycled = miner.claim_sol(&clock);
+                    transfer_lamports_safe(&miner_info, &automation.to_account_info(), recycled)?;
+                    automation.balance = automation.balance
+                        .checked_add(recycled)
+                        .ok_or(AppError::Overflow)?;
+                    msg!("Recycled {} lamports of winnings into automation balance", recycled);
+                }
+            }
+        }
+    }
+
+    // Enforce automation budget and limits
+    if let Some(automation) = &ctx.accounts.automation {
+        automation.assert_can_execute(round.id, clock.slot, miner.lifetime_rewards_sol)?;
+    }
+
     // Reset miner if joining new round
     if miner.round_id != round.id {
         // Assert miner has checkpointed prior round
@@ -256,85 +418,152 @@ pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()
         // Reset miner for new round
         miner.deployed = [0; TOTAL_BOARD];
         miner.cumulative = round.deployed;
+        miner.top_up_cumulative = [0; TOTAL_BOARD];
+        miner.top_up_deployed = [0; TOTAL_BOARD];
         miner.round_id = round.id;
     }
 
-    // Determine squares to deploy and amount
-    let mut selected_squares = [false; TOTAL_BOARD];
-    if is_automation {
-        let automation = ctx.accounts.automation.as_ref().unwrap();
-
-        // Use automation amount
-        amount = automation.amount;
-
-        // Validate minimum deployment amount
-        require!(amount >= MIN_DEPLOYMENT, AppError::AmountTooSmall);
+    // Determine the amount for each square
+    let mut amounts = [0u64; TOTAL_BOARD];
+    let mut amount = 0;
+    let mut squares = 0;
+    match request {
+        DeployAmounts::Weighted(weighted) => {
+            // Weighted amounts are chosen by the signer, never by an executor
+            require!(!is_automation, AppError::NotAuthorized);
+            amounts = weighted;
+        }
+        DeployAmounts::Uniform { amount: requested, squares: mask } => {
+            amount = requested;
+            squares = mask;
+            let mut selected_squares = [false; TOTAL_BOARD];
+            if is_automation {
+                let automation = ctx.accounts.automation.as_ref().unwrap();
+
+                // Use automation amount
+                amount = automation.amount;
+
+                // Validate minimum deployment amount
+                require!(amount >= MIN_DEPLOYMENT, AppError::AmountTooSmall);
+
+                // Determine squares based on strategy
+                let num_squares = automation.square_count.min(TOTAL_BOARD as u64);
+                let last_winning_square = if board.last_winning_square < TOTAL_BOARD as u64 {
+                    Some(board.last_winning_square as usize)
+                } else {
+                    None
+                };
+                match AutomationStrategy::from_u64(automation.strategy)? {
+                    AutomationStrategy::Preferred => {
+                        // Use automation's preferred mask
+                        for (i, selected) in selected_squares.iter_mut().enumerate() {
+                            *selected = (automation.mask & (1 << i)) != 0;
+                        }
+                    }
+                    AutomationStrategy::Random => {
+                        // Generate random mask using Keccak256 hash (same as Steel version)
+                        let hash = hashv(&[&automation.authority.to_bytes(), &round.id.to_le_bytes()]);
+                        selected_squares = generate_random_mask(num_squares, &hash);
+                    }
+                    AutomationStrategy::LeastCrowded => {
+                        selected_squares = lowest_ranked_mask(num_squares, |i| round.deployed[i]);
+                    }
+                    AutomationStrategy::Contrarian => {
+                        // Farthest from the last winner; least crowded if there is none
+                        selected_squares = match last_winning_square {
+                            Some(w) => lowest_ranked_mask(num_squares, |i| 4 - square_distance(i, w)),
+                            None => lowest_ranked_mask(num_squares, |i| round.deployed[i]),
+                        };
+                    }
+                    AutomationStrategy::Momentum => {
+                        // Closest to the last winner; least crowded if there is none
+                        selected_squares = match last_winning_square {
+                            Some(w) => lowest_ranked_mask(num_squares, |i| square_distance(i, w)),
+                            None => lowest_ranked_mask(num_squares, |i| round.deployed[i]),
+                        };
+                    }
+                    AutomationStrategy::Rotating => {
+                        let shift = (round.id.wrapping_mul(automation.rotation_step) % TOTAL_BOARD as u64) as usize;
+                        for (i, selected) in selected_squares.iter_mut().enumerate() {
+                            *selected = (automation.mask & (1 << i)) != 0;
+                        }
+                        selected_squares.rotate_right(shift);
+                    }
+                }
+            } else {
+                // Validate minimum deployment amount
+                require!(amount >= MIN_DEPLOYMENT, AppError::AmountTooSmall);
 
-        // Determine squares based on strategy
-        match AutomationStrategy::from_u64(automation.strategy) {
-            AutomationStrategy::Preferred => {
-                // Use automation's preferred mask
-                for i in 0..TOTAL_BOARD {
-                    selected_squares[i] = (automation.mask & (1 << i)) != 0;
+                // Use provided mask
+                for (i, selected) in selected_squares.iter_mut().enumerate() {
+                    *selected = (squares & (1 << i)) != 0;
                 }
             }
-            AutomationStrategy::Random => {
-                // Generate random mask using Keccak256 hash (same as Steel version)
-                let num_squares = ((automation.mask & 0xFF) as u64).min(TOTAL_BOARD as u64);
-                let hash = hashv(&[&automation.authority.to_bytes(), &round.id.to_le_bytes()]);
-                selected_squares = generate_random_mask(num_squares, &hash);
-            }
-        }
-    } else {
-        // Validate minimum deployment amount
-        require!(amount >= MIN_DEPLOYMENT, AppError::AmountTooSmall);
 
-        // Use provided mask
-        for i in 0..TOTAL_BOARD {
-            selected_squares[i] = (squares & (1 << i)) != 0;
+            for (square_id, &selected) in selected_squares.iter().enumerate() {
+                if selected {
+                    amounts[square_id] = amount;
+                }
+            }
         }
     }
+    msg!("Amount: {}, Squares: {}", amount, squares);
 
     // Deploy to selected squares
     let mut total_deployed = 0u64;
     let mut _total_squares = 0u64;
+    let mut deployed = [0u64; TOTAL_BOARD];
 
-    for (square_id, &should_deploy) in selected_squares.iter().enumerate() {
+    for (square_id, &amount) in amounts.iter().enumerate() {
         // Skip if not selected
-        if !should_deploy {
+        if amount == 0 {
             continue;
         }
 
-        // Skip if miner already deployed to this square
-        if miner.deployed[square_id] > 0 {
+        // Automation keeps one position per square per round
+        if is_automation && miner.deployed[square_id] > 0 {
             continue;
         }
 
         // For automation: exit early if not enough balance
         if is_automation {
             let automation = ctx.accounts.automation.as_ref().unwrap();
-            if total_deployed + automation.fee + amount > automation.balance {
+            if total_deployed + executor_fee + amount > automation.balance {
+                break;
+            }
+            if automation.max_per_round > 0 && total_deployed + amount > automation.max_per_round {
                 break;
             }
         }
 
-        // Record cumulative amount (snapshot before this deployment)
-        miner.cumulative[square_id] = round.deployed[square_id];
-
-        // Update miner deployment
-        miner.deployed[square_id] = amount;
+        // Update miner deployment (first deploy or top-up)
+        let is_first = miner.deploy_square(square_id, round.deployed[square_id], amount)?;
 
         // Update round state
         round.deployed[square_id] += amount;
-        round.count[square_id] += 1;
+        if is_first {
+            round.count[square_id] += 1;
+        }
 
         // Track totals
+        deployed[square_id] = amount;
         total_deployed += amount;
         _total_squares += 1;
     }
 
+    require!(total_deployed > 0, AppError::NothingDeployed);
     round.total_deployed += total_deployed;
 
+    // Update weekly leaderboard
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let epoch = board.epoch(clock.slot);
+    leaderboard.open(epoch, board.mining_start_slot);
+    if total_deployed > 0 {
+        let weekly_deployed = miner.record_leaderboard(epoch, total_deployed);
+        leaderboard.record(miner.authority, weekly_deployed);
+        leaderboard.total_deployed += total_deployed;
+    }
+
     msg!("Total deployed this tx: {} lamports", total_deployed);
     msg!("Round total deployed: {} lamports", round.total_deployed);
 
@@ -364,9 +593,9 @@ pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()
     msg!("Starting SOL transfer, is_automation: {}", is_automation);
     if is_automation {
         let automation = ctx.accounts.automation.as_mut().unwrap();
+        let automation_info = automation.to_account_info();
 
-        // Save fee before mutation
-        let executor_fee = automation.fee;
+        // Save amount before mutation
         let deploy_amount = automation.amount;
 
         // Deduct from automation balance
@@ -374,22 +603,34 @@ pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()
             .balance
             .checked_sub(total_deployed + executor_fee)
             .ok_or(AppError::InsufficientBalance)?;
-
-        // Check if should close
-        let should_close = automation.balance < deploy_amount + executor_fee;
-
-        let automation_info = ctx.accounts.automation.as_ref().unwrap().to_account_info();
+        automation.record_execution(round.id, total_deployed + executor_fee);
 
         // Transfer deployment amount to round
         transfer_lamports(&automation_info, &round_info, total_deployed)?;
 
         // Transfer fee to executor
         transfer_lamports(&automation_info, &ctx.accounts.signer.to_account_info(), executor_fee)?;
+        if automation.executor != ctx.accounts.signer.key() {
+            if let Some(registry) = ctx.accounts.executor.as_mut() {
+                registry.rounds_executed += 1;
+                registry.total_fees = registry.total_fees.saturating_add(executor_fee);
+            }
+        }
 
-        // Close automation if balance too low
-        if should_close {
-            let remaining_balance = automation_info.lamports();
-            transfer_lamports(&automation_info, &ctx.accounts.authority, remaining_balance)?;
+        // Mark depleted and refund the leftover balance if another round is not affordable.
+        // The account (and its rent) stays until the authority tops it up or closes it.
+        if automation.balance < deploy_amount + executor_fee {
+            let balance_refunded = automation.balance;
+            automation.balance = 0;
+            automation.status = AutomationStatus::Depleted as u64;
+            transfer_lamports(&automation_info, &ctx.accounts.authority, balance_refunded)?;
+
+            msg!("Automation depleted: refunded {} lamports", balance_refunded);
+            emit!(AutomationDepletedEvent {
+                authority: automation.authority,
+                balance_refunded,
+                timestamp: clock.unix_timestamp,
+            });
         }
     } else {
         msg!("Manual deploy: transferring {} lamports", total_deployed);
@@ -422,6 +663,7 @@ pub fn handler(ctx: Context<Deploy>, mut amount: u64, squares: u32) -> Result<()
         round_id,
         amount,
         squares,
+        deployed,
         total_deployed: total_deployed_final,
         timestamp: clock.unix_timestamp,
     });
@@ -451,3 +693,22 @@ fn generate_random_mask(num_squares: u64, hash: &[u8]) -> [bool; TOTAL_BOARD] {
 
     mask
 }
+
+/// Select the `num_squares` squares with the lowest rank (ties go to the lower index)
+fn lowest_ranked_mask(num_squares: u64, rank: impl Fn(usize) -> u64) -> [bool; TOTAL_BOARD] {
+    let mut order: [usize; TOTAL_BOARD] = core::array::from_fn(|i| i);
+    order.sort_by_key(|&i| (rank(i), i));
+
+    let mut mask = [false; TOTAL_BOARD];
+    for &i in order.iter().take(num_squares as usize) {
+        mask[i] = true;
+    }
+    mask
+}
+
+/// Chebyshev distance between two squares on the 5x5 board (0-4)
+fn square_distance(a: usize, b: usize) -> u64 {
+    let (ax, ay) = (a % 5, a / 5);
+    let (bx, by) = (b % 5, b / 5);
+    ax.abs_diff(bx).max(ay.abs_diff(by)) as u64
+}
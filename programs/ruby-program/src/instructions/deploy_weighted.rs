@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use super::deploy::{process_deploy, Deploy, DeployAmounts};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SquareDeployment {
+    /// Square index (0-24)
+    pub square: u8,
+    /// Lamports to deploy on the square
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DeployWeightedArgs {
+    pub deployments: Vec<SquareDeployment>,
+}
+
+/// Handler for deploy_weighted instruction
+///
+/// Same flow as `deploy`, but with an explicit amount per square. Each entry must
+/// meet MIN_DEPLOYMENT and a square may only appear once. Manual deploys only.
+pub fn handler(ctx: Context<Deploy>, args: DeployWeightedArgs) -> Result<()> {
+    require!(
+        !args.deployments.is_empty() && args.deployments.len() <= TOTAL_BOARD,
+        AppError::InvalidSquare
+    );
+
+    let mut amounts = [0u64; TOTAL_BOARD];
+    for deployment in args.deployments.iter() {
+        let square = deployment.square as usize;
+        require!(square < TOTAL_BOARD, AppError::InvalidSquare);
+        require!(amounts[square] == 0, AppError::InvalidSquare);
+        require!(deployment.amount >= MIN_DEPLOYMENT, AppError::AmountTooSmall);
+        amounts[square] = deployment.amount;
+    }
+
+    process_deploy(ctx, DeployAmounts::Weighted(amounts))
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ConfigChangedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExecuteChange<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PENDING_CONFIG_CHANGE, &[pending_change.field as u8]],
+        bump,
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+}
+
+pub fn handler(ctx: Context<ExecuteChange>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pending_change = &ctx.accounts.pending_change;
+
+    require!(
+        clock.slot >= pending_change.executable_at,
+        AppError::ConfigChangeLocked
+    );
+
+    let field = pending_change.field;
+    let new_value = pending_change.new_value;
+    let config = &mut ctx.accounts.config;
+    let old_value = config
+        .timelocked_value(field)
+        .ok_or(AppError::InvalidConfigChange)?;
+    config.set_timelocked_value(field, new_value)?;
+
+    emit!(ConfigChangedEvent {
+        field,
+        old_value,
+        new_value,
+        authority: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Config change executed: {:?} -> {:?}", field, new_value);
+
+    // Pending change account will be closed automatically due to close constraint
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::EmissionSegmentAddedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitEmissionSchedule<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = EmissionSchedule::LEN,
+        seeds = [EMISSION_SCHEDULE],
+        bump,
+    )]
+    pub emission_schedule: Box<Account<'info, EmissionSchedule>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for init_emission_schedule instruction
+///
+/// One-time setup for deployments initialized before the emission schedule
+/// existed. Seeds the launch curve that `Board::current_reward` hard-coded, so
+/// rewards continue unchanged, and fails if the schedule already exists.
+pub fn handler(ctx: Context<InitEmissionSchedule>) -> Result<()> {
+    let clock = Clock::get()?;
+    let emission_schedule = &mut ctx.accounts.emission_schedule;
+    emission_schedule.init_default();
+
+    for (index, segment) in emission_schedule.segments().iter().enumerate() {
+        emit!(EmissionSegmentAddedEvent {
+            index: index as u64,
+            segment: *segment,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("Emission schedule initialized with {} segments", emission_schedule.len);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ReferralConfigSetEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(args: InitReferralConfigArgs)]
+pub struct InitReferralConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ReferralConfig::LEN,
+        seeds = [REFERRAL_CONFIG],
+        bump,
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    /// Stats of the protocol referral sink (auto-created if needed)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ReferrerStats::LEN,
+        seeds = [REFERRER_STATS, args.protocol_referrer.as_ref()],
+        bump,
+    )]
+    pub protocol_referrer_stats: Account<'info, ReferrerStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitReferralConfigArgs {
+    /// Wallet credited with the base-rate fee of miners without a referral
+    pub protocol_referrer: Pubkey,
+}
+
+/// Handler for init_referral_config instruction
+///
+/// One-time setup for deployments initialized before referral tiers existed.
+/// Seeds the default tiers and creates the protocol sink's stats, so
+/// checkpoints of unreferred miners have somewhere to credit, and fails if the
+/// config already exists. Use set_referral_config to change it afterwards.
+pub fn handler(ctx: Context<InitReferralConfig>, args: InitReferralConfigArgs) -> Result<()> {
+    require!(args.protocol_referrer != Pubkey::default(), AppError::InvalidReferralConfig);
+
+    let clock = Clock::get()?;
+    let referral_config = &mut ctx.accounts.referral_config;
+    referral_config.init_default(args.protocol_referrer);
+
+    let protocol_referrer_stats = &mut ctx.accounts.protocol_referrer_stats;
+    if protocol_referrer_stats.referrer == Pubkey::default() {
+        protocol_referrer_stats.referrer = args.protocol_referrer;
+    }
+
+    emit!(ReferralConfigSetEvent {
+        tier_thresholds: referral_config.tier_thresholds,
+        tier_bps: referral_config.tier_bps,
+        second_level_bps: referral_config.second_level_bps,
+        change_window: referral_config.change_window,
+        protocol_referrer: referral_config.protocol_referrer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Referral config initialized");
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitReferrerStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The referrer the stats belong to - can be any wallet
+    pub referrer: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ReferrerStats::LEN,
+        seeds = [REFERRER_STATS, referrer.key().as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for init_referrer_stats instruction
+///
+/// Permissionless. Creates the stats account for referrers whose referees
+/// registered before tiering existed. Those referees are not included in
+/// `referee_count`.
+pub fn handler(ctx: Context<InitReferrerStats>) -> Result<()> {
+    let referrer_stats = &mut ctx.accounts.referrer_stats;
+    referrer_stats.referrer = ctx.accounts.referrer.key();
+    referrer_stats.referred_volume = 0;
+    referrer_stats.tier = 0;
+    referrer_stats.pending_rewards = 0;
+    referrer_stats.claimed_rewards = 0;
+    referrer_stats.referee_count = 0;
+    Ok(())
+}
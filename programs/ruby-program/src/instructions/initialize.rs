@@ -35,6 +35,34 @@ pub struct Initialize<'info> {
     )]
     pub treasury: Account<'info, Treasury>,
 
+    #[account(
+        init,
+        payer = signer,
+        space = EmissionSchedule::LEN,
+        seeds = [EMISSION_SCHEDULE],
+        bump,
+    )]
+    pub emission_schedule: Box<Account<'info, EmissionSchedule>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = ReferralConfig::LEN,
+        seeds = [REFERRAL_CONFIG],
+        bump,
+    )]
+    pub referral_config: Box<Account<'info, ReferralConfig>>,
+
+    /// Stats of the protocol referral sink (the signer)
+    #[account(
+        init,
+        payer = signer,
+        space = ReferrerStats::LEN,
+        seeds = [REFERRER_STATS, signer.key().as_ref()],
+        bump,
+    )]
+    pub protocol_referrer_stats: Box<Account<'info, ReferrerStats>>,
+
     /// The RUBY token mint
     pub mint: Account<'info, Mint>,
 
@@ -53,6 +81,15 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     config.swap_program = Pubkey::default();
     config.var_address = Pubkey::default();
     config.buffer = 0;
+    config.leaderboard_weights = LEADERBOARD_WEIGHTS_BPS;
+    config.version = CONFIG_VERSION;
+    config.fee_schedule = FeeSchedule::DEFAULT;
+    config.next_fee_schedule = FeeSchedule::DEFAULT;
+    config.next_fee_schedule_round = 0;
+    config.guardian = ctx.accounts.signer.key();
+    config.pause_flags = 0;
+    config.pending_admin = Pubkey::default();
+    config.stake_cooldown = STAKE_COOLDOWN;
 
     // Initialize board
     let board = &mut ctx.accounts.board;
@@ -63,6 +100,12 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     board.motherlode_ruby = 0;
     board.mini_motherlode_sol = 0;
     board.total_minted = 0;
+    board.motherlode_sol_odds = MOTHERLODE_SOL_ODDS;
+    board.motherlode_sol_hits = 0;
+    board.motherlode_sol_paid = 0;
+    board.last_motherlode_sol_round = 0;
+    board.last_winning_square = u64::MAX;
+    board.version = BOARD_VERSION;
 
     // Initialize treasury
     let treasury = &mut ctx.accounts.treasury;
@@ -75,6 +118,20 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     treasury.total_unclaimed = 0;
     treasury.total_refined = 0;
     treasury.leaderboard_bl = 0;
+    treasury.stake_rw_bl = 0;
+    treasury.total_stake_weight = 0;
+    treasury.presale_reserved = 0;
+    treasury.leaderboard_carry = 0;
+    treasury.version = TREASURY_VERSION;
+
+    // Initialize emission schedule with the launch curve
+    ctx.accounts.emission_schedule.init_default();
+
+    // Initialize referral config with the default tiers
+    ctx.accounts.referral_config.init_default(ctx.accounts.signer.key());
+
+    let protocol_referrer_stats = &mut ctx.accounts.protocol_referrer_stats;
+    protocol_referrer_stats.referrer = ctx.accounts.signer.key();
 
     Ok(())
 }
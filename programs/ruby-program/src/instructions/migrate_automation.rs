@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::state::*;
+use crate::utils::grow_account;
+
+#[derive(Accounts)]
+pub struct MigrateAutomation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Automation PDA, grown before it is deserialized (older layouts are shorter)
+    #[account(
+        mut,
+        seeds = [AUTOMATION, authority.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub automation: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for migrate_automation instruction
+///
+/// Grows the authority's automation account to the current layout, fills defaults
+/// for the fields added since its version, and bumps `version` to AUTOMATION_VERSION.
+pub fn handler(ctx: Context<MigrateAutomation>) -> Result<()> {
+    let automation_info = ctx.accounts.automation.to_account_info();
+    grow_account(
+        &automation_info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Automation::LEN,
+    )?;
+
+    let mut automation =
+        Automation::try_deserialize(&mut &automation_info.try_borrow_data()?[..])?;
+    require_keys_eq!(automation.authority, ctx.accounts.authority.key(), AppError::NotAuthorized);
+
+    let old_version = automation.version;
+    automation.migrate();
+    automation.try_serialize(&mut *automation_info.try_borrow_mut_data()?)?;
+
+    msg!("Automation migrated: version {} -> {}", old_version, automation.version);
+
+    Ok(())
+}
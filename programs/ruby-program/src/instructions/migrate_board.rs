@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::state::*;
+use crate::utils::grow_account;
+
+#[derive(Accounts)]
+pub struct MigrateBoard<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Board PDA, grown before it is deserialized (older layouts are shorter)
+    #[account(
+        mut,
+        seeds = [BOARD],
+        bump,
+        owner = crate::ID,
+    )]
+    pub board: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for migrate_board instruction
+///
+/// Grows the board account to the current layout, fills defaults for the fields
+/// added since its version, and bumps `version` to BOARD_VERSION.
+pub fn handler(ctx: Context<MigrateBoard>) -> Result<()> {
+    let board_info = ctx.accounts.board.to_account_info();
+    grow_account(
+        &board_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Board::LEN,
+    )?;
+
+    let mut board = Board::try_deserialize(&mut &board_info.try_borrow_data()?[..])?;
+
+    let old_version = board.version;
+    board.migrate();
+    board.try_serialize(&mut *board_info.try_borrow_mut_data()?)?;
+
+    msg!("Board migrated: version {} -> {}", old_version, board.version);
+
+    Ok(())
+}
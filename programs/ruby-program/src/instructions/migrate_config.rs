@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::state::*;
+use crate::utils::grow_account;
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Config PDA, grown before it is deserialized (older layouts are shorter)
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for migrate_config instruction
+///
+/// Grows the config account to the current layout, fills defaults for the fields
+/// added since its version, and bumps `version` to CONFIG_VERSION.
+pub fn handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    grow_account(
+        &config_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Config::LEN,
+    )?;
+
+    let mut config = Config::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+    require_keys_eq!(config.admin, ctx.accounts.admin.key(), AppError::NotAuthorized);
+
+    let old_version = config.version;
+    config.migrate();
+    config.try_serialize(&mut *config_info.try_borrow_mut_data()?)?;
+
+    msg!("Config migrated: version {} -> {}", old_version, config.version);
+
+    Ok(())
+}
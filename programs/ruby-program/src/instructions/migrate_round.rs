@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::*;
+use crate::utils::grow_account;
+
+#[derive(Accounts)]
+#[instruction(args: MigrateRoundArgs)]
+pub struct MigrateRound<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Round PDA, grown before it is deserialized (older layouts are shorter)
+    #[account(
+        mut,
+        seeds = [ROUND, &args.round_id.to_le_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateRoundArgs {
+    pub round_id: u64,
+}
+
+/// Handler for migrate_round instruction
+///
+/// Permissionless. Grows a round account created before the layout grew, so
+/// its miners can still checkpoint, with the signer paying the extra rent.
+/// Rounds already at the current layout are left unchanged.
+pub fn handler(ctx: Context<MigrateRound>, args: MigrateRoundArgs) -> Result<()> {
+    let round_info = ctx.accounts.round.to_account_info();
+    if round_info.data_len() >= Round::LEN {
+        msg!("Round {} is already at the current layout", args.round_id);
+        return Ok(());
+    }
+
+    grow_account(
+        &round_info,
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Round::LEN,
+    )?;
+
+    let mut round = Round::try_deserialize(&mut &round_info.try_borrow_data()?[..])?;
+    round.migrate_legacy();
+    round.try_serialize(&mut *round_info.try_borrow_mut_data()?)?;
+
+    msg!("Round {} migrated", args.round_id);
+
+    Ok(())
+}
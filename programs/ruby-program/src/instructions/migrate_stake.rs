@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::*;
+use crate::utils::grow_account;
+
+#[derive(Accounts)]
+#[instruction(args: MigrateStakeArgs)]
+pub struct MigrateStake<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Stake PDA, grown before it is deserialized (older layouts are shorter)
+    #[account(
+        mut,
+        seeds = [STAKE, args.authority.as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub stake: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateStakeArgs {
+    /// The owner of the stake account
+    pub authority: Pubkey,
+}
+
+/// Handler for migrate_stake instruction
+///
+/// Permissionless. Grows a stake account created before the layout grew, weighs
+/// it by its balance, matching the total stake weight seeded by
+/// migrate_treasury, and starts it with an empty unbonding queue, with the
+/// signer paying the extra rent. Run
+/// migrate_treasury first. Stakes already at STAKE_VERSION are left unchanged.
+pub fn handler(ctx: Context<MigrateStake>, args: MigrateStakeArgs) -> Result<()> {
+    let stake_info = ctx.accounts.stake.to_account_info();
+
+    grow_account(
+        &stake_info,
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Stake::LEN,
+    )?;
+
+    let mut stake = Stake::try_deserialize(&mut &stake_info.try_borrow_data()?[..])?;
+    if stake.version >= STAKE_VERSION {
+        msg!("Stake of {} is already at version {}", args.authority, stake.version);
+        return Ok(());
+    }
+    stake.migrate(Clock::get()?.unix_timestamp);
+    stake.try_serialize(&mut *stake_info.try_borrow_mut_data()?)?;
+
+    msg!("Stake of {} migrated to version {}", args.authority, STAKE_VERSION);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::state::*;
+use crate::utils::grow_account;
+
+#[derive(Accounts)]
+pub struct MigrateTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Treasury PDA, grown before it is deserialized (older layouts are shorter)
+    #[account(
+        mut,
+        seeds = [TREASURY],
+        bump,
+        owner = crate::ID,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for migrate_treasury instruction
+///
+/// Grows the treasury account to the current layout, fills defaults for the
+/// fields added since its version, and bumps `version` to TREASURY_VERSION.
+pub fn handler(ctx: Context<MigrateTreasury>) -> Result<()> {
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    grow_account(
+        &treasury_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Treasury::LEN,
+    )?;
+
+    let mut treasury = Treasury::try_deserialize(&mut &treasury_info.try_borrow_data()?[..])?;
+
+    let old_version = treasury.version;
+    treasury.migrate();
+    treasury.try_serialize(&mut *treasury_info.try_borrow_mut_data()?)?;
+
+    msg!("Treasury migrated: version {} -> {}", old_version, treasury.version);
+
+    Ok(())
+}
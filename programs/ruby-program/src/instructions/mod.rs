@@ -1,78 +1,184 @@
 // Miner instructions
 pub mod deploy;
+pub mod deploy_weighted;
 pub mod claim_token;
 pub mod claim_sol;
 pub mod checkpoint;
+pub mod checkpoint_many;
+pub mod settle;
 pub mod close;
 
 // Staking instructions
 pub mod stake_deposit;
-pub mod stake_withdraw;
+pub mod stake_unbond;
+pub mod stake_withdraw_unbonded;
 pub mod stake_claim;
+pub mod stake_lock;
+pub mod stake_release_locks;
 
 // Referral instructions
 pub mod register_referral;
 pub mod claim_referral_rewards;
+pub mod claim_referrer_rewards;
+pub mod init_referrer_stats;
+pub mod change_referrer;
+pub mod init_referral_config;
+pub mod set_referral_config;
+
+// Leaderboard instructions
+pub mod settle_leaderboard;
+
+// Presale instructions
+pub mod presale_open;
+pub mod presale_contribute;
+pub mod presale_finalize;
+pub mod presale_claim;
 
 // Automation
 pub mod automate;
 pub mod cancel_automate;
+pub mod set_automation_executor;
+pub mod register_executor;
+pub mod unregister_executor;
+pub mod migrate_automation;
 
 // Admin instructions
 pub mod initialize;
 pub mod reset;
+pub mod close_round;
 pub mod bury;
 pub mod wrap;
-pub mod set_admin;
-pub mod set_fee_collector;
-pub mod set_swap_program;
-pub mod set_var_address;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod set_bury_authority;
+pub mod queue_change;
+pub mod execute_change;
+pub mod cancel_change;
 pub mod new_var;
 pub mod set_buffer;
+pub mod set_leaderboard_weights;
+pub mod set_fee_schedule;
+pub mod add_emission_segment;
+pub mod set_pause;
+pub mod set_motherlode_sol_odds;
+pub mod migrate_config;
+pub mod migrate_treasury;
+pub mod migrate_board;
+pub mod migrate_round;
+pub mod migrate_stake;
+pub mod init_emission_schedule;
 
 // Glob re-exports are needed for Anchor macro to generate client accounts
 // The ambiguous `handler` name is intentional - each module has its own handler
 #[allow(ambiguous_glob_reexports)]
 pub use deploy::*;
 #[allow(ambiguous_glob_reexports)]
+pub use deploy_weighted::*;
+#[allow(ambiguous_glob_reexports)]
 pub use claim_token::*;
 #[allow(ambiguous_glob_reexports)]
 pub use claim_sol::*;
 #[allow(ambiguous_glob_reexports)]
 pub use checkpoint::*;
 #[allow(ambiguous_glob_reexports)]
+pub use checkpoint_many::*;
+#[allow(ambiguous_glob_reexports)]
+pub use settle::*;
+#[allow(ambiguous_glob_reexports)]
 pub use close::*;
 #[allow(ambiguous_glob_reexports)]
 pub use stake_deposit::*;
 #[allow(ambiguous_glob_reexports)]
-pub use stake_withdraw::*;
+pub use stake_unbond::*;
+#[allow(ambiguous_glob_reexports)]
+pub use stake_withdraw_unbonded::*;
 #[allow(ambiguous_glob_reexports)]
 pub use stake_claim::*;
 #[allow(ambiguous_glob_reexports)]
+pub use stake_lock::*;
+#[allow(ambiguous_glob_reexports)]
+pub use stake_release_locks::*;
+#[allow(ambiguous_glob_reexports)]
 pub use register_referral::*;
 #[allow(ambiguous_glob_reexports)]
 pub use claim_referral_rewards::*;
 #[allow(ambiguous_glob_reexports)]
+pub use claim_referrer_rewards::*;
+#[allow(ambiguous_glob_reexports)]
+pub use init_referrer_stats::*;
+#[allow(ambiguous_glob_reexports)]
+pub use change_referrer::*;
+#[allow(ambiguous_glob_reexports)]
+pub use init_referral_config::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_referral_config::*;
+#[allow(ambiguous_glob_reexports)]
+pub use settle_leaderboard::*;
+#[allow(ambiguous_glob_reexports)]
+pub use presale_open::*;
+#[allow(ambiguous_glob_reexports)]
+pub use presale_contribute::*;
+#[allow(ambiguous_glob_reexports)]
+pub use presale_finalize::*;
+#[allow(ambiguous_glob_reexports)]
+pub use presale_claim::*;
+#[allow(ambiguous_glob_reexports)]
 pub use automate::*;
 #[allow(ambiguous_glob_reexports)]
 pub use cancel_automate::*;
 #[allow(ambiguous_glob_reexports)]
+pub use set_automation_executor::*;
+#[allow(ambiguous_glob_reexports)]
+pub use register_executor::*;
+#[allow(ambiguous_glob_reexports)]
+pub use unregister_executor::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_automation::*;
+#[allow(ambiguous_glob_reexports)]
 pub use initialize::*;
 #[allow(ambiguous_glob_reexports)]
 pub use reset::*;
 #[allow(ambiguous_glob_reexports)]
+pub use close_round::*;
+#[allow(ambiguous_glob_reexports)]
 pub use bury::*;
 #[allow(ambiguous_glob_reexports)]
 pub use wrap::*;
 #[allow(ambiguous_glob_reexports)]
-pub use set_admin::*;
+pub use propose_admin::*;
 #[allow(ambiguous_glob_reexports)]
-pub use set_fee_collector::*;
+pub use accept_admin::*;
 #[allow(ambiguous_glob_reexports)]
-pub use set_swap_program::*;
+pub use set_bury_authority::*;
 #[allow(ambiguous_glob_reexports)]
-pub use set_var_address::*;
+pub use queue_change::*;
+#[allow(ambiguous_glob_reexports)]
+pub use execute_change::*;
+#[allow(ambiguous_glob_reexports)]
+pub use cancel_change::*;
 #[allow(ambiguous_glob_reexports)]
 pub use new_var::*;
 #[allow(ambiguous_glob_reexports)]
 pub use set_buffer::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_leaderboard_weights::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_fee_schedule::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_motherlode_sol_odds::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_config::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_treasury::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_board::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_round::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_stake::*;
+#[allow(ambiguous_glob_reexports)]
+pub use init_emission_schedule::*;
+#[allow(ambiguous_glob_reexports)]
+pub use add_emission_segment::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_pause::*;
@@ -109,8 +109,10 @@ pub fn handler(ctx: Context<NewVar>, args: NewVarArgs) -> Result<()> {
         signer_seeds,
     )?;
 
-    // Update config with the new var address
-    ctx.accounts.config.var_address = ctx.accounts.var_account.key();
+    // Set the var address on first-time setup; later changes go through queue_change
+    if ctx.accounts.config.var_address == Pubkey::default() {
+        ctx.accounts.config.var_address = ctx.accounts.var_account.key();
+    }
 
     Ok(())
 }
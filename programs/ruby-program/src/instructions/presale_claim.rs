@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::PresaleClaimEvent;
+use crate::state::*;
+use crate::utils::transfer_lamports;
+
+#[derive(Accounts)]
+pub struct PresaleClaim<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [PRESALE],
+        bump,
+    )]
+    pub presale: Account<'info, Presale>,
+
+    /// Contribution account - closed once claimed
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PRESALE_CONTRIBUTION, authority.key().as_ref()],
+        bump,
+    )]
+    pub contribution: Account<'info, PresaleContribution>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        address = MINT_ADDRESS
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// Recipient's token account (auto-created if needed)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = authority
+    )]
+    pub recipient: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for presale_claim instruction
+///
+/// Finalized presale: mints RUBY at PRESALE_RATE through the treasury mint authority.
+/// Cancelled presale: refunds the contributed SOL from escrow.
+pub fn handler(ctx: Context<PresaleClaim>) -> Result<()> {
+    let clock = Clock::get()?;
+    let presale_info = ctx.accounts.presale.to_account_info();
+    let presale = &mut ctx.accounts.presale;
+    let contributed = ctx.accounts.contribution.amount;
+
+    let mut token_amount = 0;
+    let mut refund_amount = 0;
+
+    match PresaleStatus::from_u64(presale.status) {
+        PresaleStatus::Open => return err!(AppError::PresaleNotSettled),
+        PresaleStatus::Finalized => {
+            ctx.accounts.config.assert_not_paused(PAUSE_CLAIM)?;
+
+            token_amount = Presale::token_amount(contributed).ok_or(AppError::Overflow)?;
+
+            // Respect the hard supply cap
+            let new_supply = ctx.accounts.mint.supply
+                .checked_add(token_amount)
+                .ok_or(AppError::Overflow)?;
+            require!(new_supply <= MAX_SUPPLY, AppError::MaxSupplyReached);
+
+            presale.total_claimed = presale.total_claimed
+                .checked_add(token_amount)
+                .ok_or(AppError::Overflow)?;
+
+            // Release the reservation made at finalize
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.presale_reserved = treasury.presale_reserved.saturating_sub(token_amount);
+
+            if token_amount > 0 {
+                let treasury_bump = ctx.bumps.treasury;
+                let signer_seeds: &[&[&[u8]]] = &[&[TREASURY, &[treasury_bump]]];
+
+                let cpi_accounts = token::MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                    authority: ctx.accounts.treasury.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                );
+                token::mint_to(cpi_ctx, token_amount)?;
+            }
+
+            msg!("Presale claim: minted {} RUBY for {} lamports", token_amount, contributed);
+        }
+        PresaleStatus::Cancelled => {
+            ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
+
+            refund_amount = contributed;
+            presale.total_refunded = presale.total_refunded
+                .checked_add(refund_amount)
+                .ok_or(AppError::Overflow)?;
+
+            if refund_amount > 0 {
+                transfer_lamports(
+                    &presale_info,
+                    &ctx.accounts.authority.to_account_info(),
+                    refund_amount,
+                )?;
+            }
+
+            msg!("Presale refund: {} lamports", refund_amount);
+        }
+    }
+
+    emit!(PresaleClaimEvent {
+        authority: ctx.accounts.authority.key(),
+        contributed,
+        token_amount,
+        refund_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Contribution account will be closed automatically due to close constraint
+    Ok(())
+}
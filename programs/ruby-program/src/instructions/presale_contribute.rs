@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::PresaleContributionEvent;
+use crate::state::*;
+use crate::utils::transfer_sol_cpi;
+
+#[derive(Accounts)]
+pub struct PresaleContribute<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [PRESALE],
+        bump,
+    )]
+    pub presale: Account<'info, Presale>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PresaleContribution::LEN,
+        seeds = [PRESALE_CONTRIBUTION, authority.key().as_ref()],
+        bump,
+    )]
+    pub contribution: Account<'info, PresaleContribution>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PresaleContribute>, amount: u64) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_PRESALE)?;
+
+    let clock = Clock::get()?;
+    let presale = &mut ctx.accounts.presale;
+    let contribution = &mut ctx.accounts.contribution;
+
+    require!(amount > 0, AppError::AmountTooSmall);
+    require!(presale.is_accepting(clock.slot), AppError::PresaleNotActive);
+
+    // Initialize contribution on first deposit
+    if contribution.authority == Pubkey::default() {
+        contribution.authority = ctx.accounts.authority.key();
+        contribution.amount = 0;
+        contribution.created_at = clock.unix_timestamp;
+        presale.contributors = presale.contributors
+            .checked_add(1)
+            .ok_or(AppError::Overflow)?;
+    }
+
+    // Enforce per-wallet cap
+    let total_contributed = contribution.amount
+        .checked_add(amount)
+        .ok_or(AppError::Overflow)?;
+    require!(
+        total_contributed <= PRESALE_WALLET_CAP,
+        AppError::PresaleWalletCapExceeded
+    );
+
+    // Enforce total cap
+    let total_raised = presale.total_raised
+        .checked_add(amount)
+        .ok_or(AppError::Overflow)?;
+    require!(
+        total_raised <= PRESALE_TOTAL_CAP,
+        AppError::PresaleCapExceeded
+    );
+
+    contribution.amount = total_contributed;
+    presale.total_raised = total_raised;
+
+    // Escrow SOL in the presale account until finalize
+    transfer_sol_cpi(
+        ctx.accounts.authority.to_account_info(),
+        presale.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    emit!(PresaleContributionEvent {
+        authority: ctx.accounts.authority.key(),
+        amount,
+        total_contributed,
+        total_raised,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Presale contribution: {} lamports (wallet total: {}, raised: {})",
+        amount, total_contributed, total_raised);
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::PresaleFinalizedEvent;
+use crate::state::*;
+use crate::utils::transfer_lamports;
+
+#[derive(Accounts)]
+pub struct PresaleFinalize<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [PRESALE],
+        bump,
+    )]
+    pub presale: Account<'info, Presale>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(address = MINT_ADDRESS)]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: Validated against presale.liquidity_recipient
+    #[account(
+        mut,
+        address = presale.liquidity_recipient @ AppError::NotAuthorized,
+    )]
+    pub liquidity_recipient: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PresaleFinalizeArgs {
+    /// Cancel the presale instead of finalizing it; contributors can then claim refunds.
+    pub cancel: bool,
+}
+
+/// Handler for presale_finalize instruction
+///
+/// Finalizing sends the raised SOL to the liquidity recipient, reserves the RUBY owed
+/// to contributors out of MAX_SUPPLY and lets them claim it. Cancelling keeps the SOL
+/// escrowed so contributors can claim refunds.
+pub fn handler(ctx: Context<PresaleFinalize>, args: PresaleFinalizeArgs) -> Result<()> {
+    let clock = Clock::get()?;
+    let presale_info = ctx.accounts.presale.to_account_info();
+    let presale = &mut ctx.accounts.presale;
+
+    require!(
+        PresaleStatus::from_u64(presale.status) == PresaleStatus::Open,
+        AppError::PresaleAlreadySettled
+    );
+
+    let mut liquidity_amount = 0;
+    if args.cancel {
+        presale.status = PresaleStatus::Cancelled as u64;
+        msg!("Presale cancelled: {} lamports available for refunds", presale.total_raised);
+    } else {
+        // Sale can close early once the total cap is filled
+        require!(
+            clock.slot >= presale.end_slot || presale.total_raised >= PRESALE_TOTAL_CAP,
+            AppError::PresaleStillActive
+        );
+
+        // Reserve the full allocation so mining emissions cannot eat into it
+        let allocation = Presale::token_amount(presale.total_raised).ok_or(AppError::Overflow)?;
+        let reserved_supply = ctx.accounts.mint.supply
+            .checked_add(allocation)
+            .ok_or(AppError::Overflow)?;
+        require!(reserved_supply <= MAX_SUPPLY, AppError::MaxSupplyReached);
+        ctx.accounts.treasury.presale_reserved = allocation;
+
+        presale.status = PresaleStatus::Finalized as u64;
+        liquidity_amount = presale.total_raised;
+
+        if liquidity_amount > 0 {
+            transfer_lamports(
+                &presale_info,
+                &ctx.accounts.liquidity_recipient.to_account_info(),
+                liquidity_amount,
+            )?;
+        }
+        msg!("Presale finalized: {} lamports sent to liquidity, {} RUBY reserved",
+            liquidity_amount, allocation);
+    }
+
+    emit!(PresaleFinalizedEvent {
+        cancelled: args.cancel,
+        total_raised: presale.total_raised,
+        liquidity_amount,
+        contributors: presale.contributors,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::PresaleOpenedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct PresaleOpen<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Presale::LEN,
+        seeds = [PRESALE],
+        bump,
+    )]
+    pub presale: Account<'info, Presale>,
+
+    /// CHECK: Receives the raised SOL for initial liquidity at finalize
+    pub liquidity_recipient: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PresaleOpenArgs {
+    pub start_slot: u64,
+    pub end_slot: u64,
+}
+
+pub fn handler(ctx: Context<PresaleOpen>, args: PresaleOpenArgs) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Validate contribution window
+    require!(
+        args.end_slot > args.start_slot && args.end_slot > clock.slot,
+        AppError::InvalidEndSlot
+    );
+
+    let presale = &mut ctx.accounts.presale;
+    presale.start_slot = args.start_slot;
+    presale.end_slot = args.end_slot;
+    presale.liquidity_recipient = ctx.accounts.liquidity_recipient.key();
+    presale.total_raised = 0;
+    presale.total_claimed = 0;
+    presale.total_refunded = 0;
+    presale.contributors = 0;
+    presale.status = PresaleStatus::Open as u64;
+
+    emit!(PresaleOpenedEvent {
+        start_slot: presale.start_slot,
+        end_slot: presale.end_slot,
+        liquidity_recipient: presale.liquidity_recipient,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Presale opened: slots [{}, {})", presale.start_slot, presale.end_slot);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ConfigChangedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeAdminArgs {
+    /// The proposed admin (Pubkey::default() cancels a pending proposal)
+    pub new_admin: Pubkey,
+}
+
+pub fn handler(ctx: Context<ProposeAdmin>, args: ProposeAdminArgs) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_value = config.pending_admin;
+    config.pending_admin = args.new_admin;
+
+    emit!(ConfigChangedEvent {
+        field: ConfigField::PendingAdmin,
+        old_value: ConfigValue::Pubkey(old_value),
+        new_value: ConfigValue::Pubkey(args.new_admin),
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ConfigChangeQueuedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(args: QueueChangeArgs)]
+pub struct QueueChange<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// One pending change per field; cancel before queueing a replacement
+    #[account(
+        init,
+        payer = admin,
+        space = PendingConfigChange::LEN,
+        seeds = [PENDING_CONFIG_CHANGE, &[args.field as u8]],
+        bump,
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QueueChangeArgs {
+    pub field: ConfigField,
+    pub new_value: ConfigValue,
+    /// Slots before the change can be executed (at least MIN_CONFIG_CHANGE_DELAY_SLOTS).
+    pub delay_slots: u64,
+}
+
+pub fn handler(ctx: Context<QueueChange>, args: QueueChangeArgs) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        args.delay_slots >= MIN_CONFIG_CHANGE_DELAY_SLOTS,
+        AppError::InvalidConfigChange
+    );
+
+    let old_value = ctx.accounts.config
+        .timelocked_value(args.field)
+        .ok_or(AppError::InvalidConfigChange)?;
+    Config::validate_timelocked_value(args.field, &args.new_value)?;
+
+    let executable_at = clock.slot
+        .checked_add(args.delay_slots)
+        .ok_or(AppError::Overflow)?;
+
+    let pending_change = &mut ctx.accounts.pending_change;
+    pending_change.field = args.field;
+    pending_change.new_value = args.new_value;
+    pending_change.queued_at = clock.slot;
+    pending_change.executable_at = executable_at;
+
+    emit!(ConfigChangeQueuedEvent {
+        field: args.field,
+        old_value,
+        new_value: args.new_value,
+        executable_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Config change queued: {:?} -> {:?} at slot {}", args.field, args.new_value, executable_at);
+
+    Ok(())
+}
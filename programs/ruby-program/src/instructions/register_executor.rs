@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::ExecutorRegisteredEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RegisterExecutor<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Executor::LEN,
+        seeds = [EXECUTOR, authority.key().as_ref()],
+        bump,
+    )]
+    pub executor: Account<'info, Executor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RegisterExecutorArgs {
+    /// Fee charged per deploy, in lamports.
+    pub fee: u64,
+}
+
+/// Handler for register_executor instruction
+///
+/// Registers the signer as an open-market executor, or updates its published fee.
+pub fn handler(ctx: Context<RegisterExecutor>, args: RegisterExecutorArgs) -> Result<()> {
+    let clock = Clock::get()?;
+    let executor = &mut ctx.accounts.executor;
+
+    if executor.authority == Pubkey::default() {
+        executor.authority = ctx.accounts.authority.key();
+        executor.registered_at = clock.unix_timestamp;
+        executor.rounds_executed = 0;
+        executor.total_fees = 0;
+    }
+    executor.fee = args.fee;
+
+    emit!(ExecutorRegisteredEvent {
+        executor: executor.authority,
+        fee: executor.fee,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Executor {} registered with fee {}", executor.authority, executor.fee);
+
+    Ok(())
+}
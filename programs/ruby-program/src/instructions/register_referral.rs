@@ -3,6 +3,8 @@ use crate::constants::*;
 use crate::errors::AppError;
 use crate::events::ReferralRegisteredEvent;
 use crate::state::*;
+use super::change_referrer::second_referrer_of;
+use super::checkpoint::load_referral;
 
 #[derive(Accounts)]
 pub struct RegisterReferral<'info> {
@@ -21,6 +23,24 @@ pub struct RegisterReferral<'info> {
     )]
     pub referral: Account<'info, Referral>,
 
+    /// Stats of the referrer (auto-created if needed)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ReferrerStats::LEN,
+        seeds = [REFERRER_STATS, referrer.key().as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    /// CHECK: The referrer's own referral, to set the second-level referrer.
+    /// Deserialized in the handler (may not exist)
+    #[account(
+        seeds = [REFERRAL, referrer.key().as_ref()],
+        bump,
+    )]
+    pub referrer_referral: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -40,6 +60,16 @@ pub fn handler(ctx: Context<RegisterReferral>) -> Result<()> {
     referral.authority = authority.key();
     referral.referrer = referrer.key();
     referral.created_at = clock.unix_timestamp;
+    let referrer_referral = load_referral(&ctx.accounts.referrer_referral.to_account_info())?;
+    referral.second_referrer = second_referrer_of(authority.key(), referrer_referral.as_ref());
+    referral.second_pending_rewards = 0;
+    referral.referrer_changed = false;
+
+    let referrer_stats = &mut ctx.accounts.referrer_stats;
+    if referrer_stats.referrer == Pubkey::default() {
+        referrer_stats.referrer = referrer.key();
+    }
+    referrer_stats.referee_count += 1;
 
     // Emit event
     emit!(ReferralRegisteredEvent {
@@ -1,8 +1,8 @@
 use crate::constants::*;
 use crate::errors::AppError;
-use crate::events::ResetEvent;
+use crate::events::{ResetEvent, StakeRewardsEvent};
 use crate::state::*;
-use crate::utils::transfer_lamports;
+use crate::utils::{transfer_lamports, Numeric};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
@@ -56,6 +56,22 @@ pub struct Reset<'info> {
     )]
     pub treasury: Account<'info, Treasury>,
 
+    #[account(
+        seeds = [EMISSION_SCHEDULE],
+        bump,
+    )]
+    pub emission_schedule: Box<Account<'info, EmissionSchedule>>,
+
+    /// Leaderboard for the week the round ended in, credited with its share (auto-created if needed)
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = Leaderboard::LEN,
+        seeds = [LEADERBOARD, &board.epoch(board.end_slot).to_le_bytes()],
+        bump,
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
     /// Treasury token account for RUBY (ATA, auto-created if needed)
     #[account(
         init_if_needed,
@@ -82,13 +98,20 @@ pub struct Reset<'info> {
 }
 
 pub fn handler(ctx: Context<Reset>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_RESET)?;
+
     let clock = Clock::get()?;
 
     // Clone account infos FIRST before any borrows
     let current_round_info = ctx.accounts.current_round.to_account_info();
     let treasury_info = ctx.accounts.treasury.to_account_info();
 
+    // Fee schedule in force for this round
+    let fees = ctx.accounts.config.fee_schedule_at(ctx.accounts.current_round.id);
+
     let board = &mut ctx.accounts.board;
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.open(board.epoch(board.end_slot), board.mining_start_slot);
     let current_round = &mut ctx.accounts.current_round;
     let next_round = &mut ctx.accounts.next_round;
     let treasury = &mut ctx.accounts.treasury;
@@ -145,6 +168,7 @@ pub fn handler(ctx: Context<Reset>) -> Result<()> {
         board.round_id += 1;
         board.start_slot = clock.slot + 1;
         board.end_slot = u64::MAX;
+        board.last_winning_square = u64::MAX;
 
         // Emit reset event (no RNG scenario)
         emit!(ResetEvent {
@@ -155,10 +179,14 @@ pub fn handler(ctx: Context<Reset>) -> Result<()> {
             top_miner: Pubkey::default(),
             num_winners: 0,
             motherlode: 0,
+            mini_motherlode_sol: 0,
+            motherlode_sol: 0,
+            motherlode_sol_pool: treasury.motherlode_sol_bl,
             total_deployed: current_round.total_deployed,
             total_vaulted: 0,
             total_winnings: 0,
             total_minted: 0,
+            fee_schedule: fees,
             timestamp: clock.unix_timestamp,
         });
 
@@ -167,6 +195,7 @@ pub fn handler(ctx: Context<Reset>) -> Result<()> {
 
     // Get winning square
     let winning_square = current_round.winning_square(r);
+    board.last_winning_square = winning_square as u64;
 
     // If no one deployed on winning square, 100% treasury
     if current_round.deployed[winning_square] == 0 {
@@ -195,57 +224,84 @@ pub fn handler(ctx: Context<Reset>) -> Result<()> {
             top_miner: Pubkey::default(),
             num_winners: 0,
             motherlode: 0,
+            mini_motherlode_sol: 0,
+            motherlode_sol: 0,
+            motherlode_sol_pool: treasury.motherlode_sol_bl,
             total_deployed: current_round.total_deployed,
             total_vaulted: total,
             total_winnings: 0,
             total_minted: 0,
+            fee_schedule: fees,
             timestamp: clock.unix_timestamp,
         });
 
         return Ok(());
     }
 
-    // Calculate distribution from losing squares
-    // RUBY Fee Distribution:
+    // Calculate distribution from losing squares using the fee schedule in force
+    // Default RUBY Fee Distribution:
     // - 88% to winners
     // - 8% buyback and burn
     // - 2% strategic reserve (leaderboard + mini-motherlode)
     // - 2% motherlode pool
     let losing_squares_total = current_round.calculate_total_winnings(winning_square);
 
-    let winners_share = losing_squares_total * 88 / 100; // 88%
-    let buyback_amount = losing_squares_total * 8 / 100; // 8%
-    let reserve_amount = losing_squares_total * 2 / 100; // 2% strategic reserve
-    let motherlode_sol_amount = losing_squares_total * 2 / 100; // 2% motherlode pool
-    let platform_fee = winners_share / 100; // 1% of winners amount
+    let winners_share = FeeSchedule::share(losing_squares_total, fees.winners_bps);
+    let mut buyback_amount = FeeSchedule::share(losing_squares_total, fees.buyback_bps);
+    let reserve_amount = FeeSchedule::share(losing_squares_total, fees.reserve_bps);
+    let motherlode_sol_amount = FeeSchedule::share(losing_squares_total, fees.motherlode_bps);
+    let mut stake_amount = FeeSchedule::share(losing_squares_total, fees.stake_bps);
+    let platform_fee = FeeSchedule::share(winners_share, fees.platform_fee_bps);
     let winners_amount = winners_share - platform_fee;
 
+    // Route the staking share to stakers.
+    // Falls back to buyback while nothing is staked so the SOL isn't stranded.
+    if treasury.total_stake_weight == 0 {
+        buyback_amount += stake_amount;
+        stake_amount = 0;
+    } else if stake_amount > 0 {
+        let stake_factor = Numeric::from_fraction(stake_amount, treasury.total_stake_weight);
+        treasury.stake_rewards_factor += stake_factor;
+        treasury.stake_rw_bl += stake_amount;
+    }
+
     current_round.total_winnings = winners_amount;
-    current_round.total_vaulted = buyback_amount + reserve_amount + motherlode_sol_amount;
-    current_round.total_stake_rewards = 0; // RUBY doesn't use staking rewards from mining
+    current_round.total_vaulted =
+        buyback_amount + reserve_amount + motherlode_sol_amount + stake_amount;
+    current_round.total_stake_rewards = stake_amount;
 
     // Update treasury balances
     treasury.buyback_bl += buyback_amount;
     treasury.reserve_bl += reserve_amount;
     treasury.motherlode_sol_bl += motherlode_sol_amount;
 
-    // Split reserve between leaderboard and mini-motherlode
+    // Split reserve between leaderboard and mini-motherlode. The leaderboard share
+    // goes to the pool of the week this round ended in.
     let leaderboard_share = reserve_amount / 2;
-    treasury.leaderboard_bl += leaderboard_share;
+    leaderboard.credit(leaderboard_share, treasury);
     board.mini_motherlode_sol += reserve_amount - leaderboard_share;
 
-    msg!("RUBY distribution: winners={}, buyback={}, reserve={}, motherlode_sol={}",
-        winners_amount, buyback_amount, reserve_amount, motherlode_sol_amount);
+    msg!("RUBY distribution: winners={}, buyback={}, reserve={}, motherlode_sol={}, stake={}",
+        winners_amount, buyback_amount, reserve_amount, motherlode_sol_amount, stake_amount);
+
+    if stake_amount > 0 {
+        emit!(StakeRewardsEvent {
+            round_id: current_round.id,
+            amount: stake_amount,
+            total_staked: treasury.total_staked,
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
     // Calculate RUBY reward based on emission schedule
     let current_supply = mint.supply;
-    let reward_per_round = board.current_reward(clock.slot);
+    let reward_per_round = ctx.accounts.emission_schedule.reward_at(board.mining_start_slot, clock.slot);
     msg!("Current mint supply: {}", current_supply);
     msg!("MAX_SUPPLY: {}", MAX_SUPPLY);
     msg!("Current reward per round: {}", reward_per_round);
 
-    let mint_amount = MAX_SUPPLY
-        .saturating_sub(current_supply)
+    let mint_amount = treasury
+        .mintable_supply(current_supply)
         .min(reward_per_round);
     msg!("Calculated mint_amount: {}", mint_amount);
 
@@ -261,41 +317,61 @@ pub fn handler(ctx: Context<Reset>) -> Result<()> {
     msg!("Motherlode RUBY pool: {} RUBY", board.motherlode_ruby / ONE_TOKEN);
 
     // Payout the motherlode if it was activated.
+    let mut motherlode_amount = 0;
     if current_round.did_hit_motherlode(r) {
-        // RUBY motherlode payout (in RUBY tokens)
-        let motherlode_ruby = board.motherlode_ruby;
-        current_round.motherlode = motherlode_ruby;
-        board.motherlode_ruby = 0;
+        // RUBY motherlode payout (minted as RUBY tokens, bounded by MAX_SUPPLY)
+        motherlode_amount = treasury
+            .mintable_supply(current_supply)
+            .saturating_sub(mint_amount)
+            .min(board.motherlode_ruby);
+        current_round.motherlode = motherlode_amount;
+        board.motherlode_ruby -= motherlode_amount;
 
         // Also payout mini-motherlode SOL
         let mini_ml_sol = board.mini_motherlode_sol;
         if mini_ml_sol > 0 {
             transfer_lamports(&treasury_info, &current_round_info, mini_ml_sol)?;
+            current_round.mini_motherlode_sol = mini_ml_sol;
             board.mini_motherlode_sol = 0;
             msg!("Mini-Motherlode hit! Transferred {} SOL to winners", mini_ml_sol);
         }
 
-        msg!("Motherlode hit! {} RUBY to winners", motherlode_ruby / ONE_TOKEN);
+        msg!("Motherlode hit! {} RUBY to winners", motherlode_amount / ONE_TOKEN);
+    }
+
+    // Payout the SOL motherlode pool on its own trigger.
+    if current_round.did_hit_motherlode_sol(board.motherlode_sol_odds) {
+        let motherlode_sol = treasury.motherlode_sol_bl;
+        if motherlode_sol > 0 {
+            transfer_lamports(&treasury_info, &current_round_info, motherlode_sol)?;
+            treasury.motherlode_sol_bl = 0;
+            current_round.motherlode_sol = motherlode_sol;
+            board.motherlode_sol_hits += 1;
+            board.motherlode_sol_paid += motherlode_sol;
+            board.last_motherlode_sol_round = current_round.id;
+            msg!("SOL Motherlode hit! Transferred {} SOL to winners", motherlode_sol);
+        }
     }
 
     // Perform minting operations (borrow accounts after treasury mutations)
-    msg!("Checking if mint_amount > 0: {}", mint_amount > 0);
-    if mint_amount > 0 {
-        msg!("Minting {} tokens to treasury", mint_amount);
+    let total_mint_amount = mint_amount + motherlode_amount;
+    msg!("Checking if total_mint_amount > 0: {}", total_mint_amount > 0);
+    if total_mint_amount > 0 {
+        msg!("Minting {} tokens to treasury", total_mint_amount);
         let treasury_bump = ctx.bumps.treasury;
         let signer_seeds: &[&[&[u8]]] = &[&[TREASURY, &[treasury_bump]]];
 
         let cpi_accounts = token::MintTo {
             mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.treasury_tokens.to_account_info(),
-            authority: ctx.accounts.treasury.to_account_info(),
+            authority: treasury_info.clone(),
         };
         let cpi_ctx = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             cpi_accounts,
             signer_seeds,
         );
-        token::mint_to(cpi_ctx, mint_amount)?;
+        token::mint_to(cpi_ctx, total_mint_amount)?;
     }
 
     // Initialize next round
@@ -313,7 +389,7 @@ pub fn handler(ctx: Context<Reset>) -> Result<()> {
     }
 
     // Update board total minted
-    board.total_minted += mint_amount;
+    board.total_minted += total_mint_amount;
 
     // Transfer platform fee to fee_collector (1% of winners amount)
     if platform_fee > 0 {
@@ -333,10 +409,14 @@ pub fn handler(ctx: Context<Reset>) -> Result<()> {
         top_miner: current_round.top_miner,
         num_winners: current_round.count[winning_square],
         motherlode: current_round.motherlode,
+        mini_motherlode_sol: current_round.mini_motherlode_sol,
+        motherlode_sol: current_round.motherlode_sol,
+        motherlode_sol_pool: treasury.motherlode_sol_bl,
         total_deployed: current_round.total_deployed,
         total_vaulted: current_round.total_vaulted,
         total_winnings: current_round.total_winnings,
-        total_minted: mint_amount,
+        total_minted: total_mint_amount,
+        fee_schedule: fees,
         timestamp: clock.unix_timestamp,
     });
 
@@ -357,4 +437,7 @@ fn initialize_next_round(round: &mut Round, board: &Board, rent_payer: &Pubkey)
     round.total_vaulted = 0;
     round.total_stake_rewards = 0;
     round.total_winnings = 0;
+    round.mini_motherlode_sol = 0;
+    round.motherlode_sol = 0;
+    round.total_token_credited = 0;
 }
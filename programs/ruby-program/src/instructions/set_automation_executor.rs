@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::AutomationExecutorChangedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetAutomationExecutor<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUTOMATION, authority.key().as_ref()],
+        bump,
+        has_one = authority @ AppError::NotAuthorized,
+    )]
+    pub automation: Account<'info, Automation>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetAutomationExecutorArgs {
+    pub new_executor: Pubkey,
+}
+
+/// Handler for set_automation_executor instruction
+///
+/// Rotates the executor without touching balance, budgets or status.
+pub fn handler(ctx: Context<SetAutomationExecutor>, args: SetAutomationExecutorArgs) -> Result<()> {
+    require!(args.new_executor != Pubkey::default(), AppError::NotAuthorized);
+
+    let automation = &mut ctx.accounts.automation;
+    let old_executor = automation.executor;
+    automation.executor = args.new_executor;
+
+    emit!(AutomationExecutorChangedEvent {
+        authority: automation.authority,
+        old_executor,
+        new_executor: args.new_executor,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ConfigChangedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetBuryAuthority<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetBuryAuthorityArgs {
+    pub new_bury_authority: Pubkey,
+}
+
+pub fn handler(ctx: Context<SetBuryAuthority>, args: SetBuryAuthorityArgs) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old_value = config.bury_authority;
+    config.bury_authority = args.new_bury_authority;
+
+    emit!(ConfigChangedEvent {
+        field: ConfigField::BuryAuthority,
+        old_value: ConfigValue::Pubkey(old_value),
+        new_value: ConfigValue::Pubkey(args.new_bury_authority),
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
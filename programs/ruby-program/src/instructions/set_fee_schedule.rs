@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::FeeScheduleSetEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetFeeSchedule<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [BOARD],
+        bump,
+    )]
+    pub board: Account<'info, Board>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetFeeScheduleArgs {
+    pub fee_schedule: FeeSchedule,
+}
+
+/// Handler for set_fee_schedule instruction
+///
+/// The new schedule is queued and applies from the next round, so the round
+/// currently accepting deployments keeps the split it opened with.
+pub fn handler(ctx: Context<SetFeeSchedule>, args: SetFeeScheduleArgs) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.config;
+    let round_id = ctx.accounts.board.round_id;
+
+    require!(args.fee_schedule.is_valid(), AppError::InvalidFeeSchedule);
+
+    // Promote a previously queued schedule that is already in force
+    config.fee_schedule = config.fee_schedule_at(round_id);
+
+    let effective_round = round_id + 1;
+    config.next_fee_schedule = args.fee_schedule;
+    config.next_fee_schedule_round = effective_round;
+
+    emit!(FeeScheduleSetEvent {
+        fee_schedule: args.fee_schedule,
+        effective_round,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Fee schedule queued for round {}", effective_round);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetLeaderboardWeights<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetLeaderboardWeightsArgs {
+    /// Payout weights by rank, in basis points (must sum to 10,000)
+    pub weights: [u64; LEADERBOARD_SIZE],
+}
+
+pub fn handler(ctx: Context<SetLeaderboardWeights>, args: SetLeaderboardWeightsArgs) -> Result<()> {
+    let total = args.weights.iter().try_fold(0u64, |acc, &w| acc.checked_add(w));
+    require!(total == Some(10_000), AppError::InvalidLeaderboardWeights);
+
+    ctx.accounts.config.leaderboard_weights = args.weights;
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ConfigChangedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetMotherlodeSolOdds<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [BOARD],
+        bump,
+    )]
+    pub board: Account<'info, Board>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetMotherlodeSolOddsArgs {
+    /// SOL motherlode odds (1 in N rounds, at least MIN_MOTHERLODE_SOL_ODDS, 0 disables)
+    pub odds: u64,
+}
+
+pub fn handler(ctx: Context<SetMotherlodeSolOdds>, args: SetMotherlodeSolOddsArgs) -> Result<()> {
+    require!(
+        args.odds == 0 || args.odds >= MIN_MOTHERLODE_SOL_ODDS,
+        AppError::InvalidMotherlodeOdds
+    );
+
+    let board = &mut ctx.accounts.board;
+    let old_value = board.motherlode_sol_odds;
+    board.motherlode_sol_odds = args.odds;
+
+    emit!(ConfigChangedEvent {
+        field: ConfigField::MotherlodeSolOdds,
+        old_value: ConfigValue::U64(old_value),
+        new_value: ConfigValue::U64(args.odds),
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
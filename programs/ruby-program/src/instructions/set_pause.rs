@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::PauseChangedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    /// Admin or guardian
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPauseArgs {
+    pub pause_flags: u64,
+}
+
+/// Handler for set_pause instruction
+///
+/// The admin can set any flags. The guardian can only add flags; unpausing
+/// requires the admin.
+pub fn handler(ctx: Context<SetPause>, args: SetPauseArgs) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.config;
+    let authority = ctx.accounts.authority.key();
+    let previous_flags = config.pause_flags;
+
+    if authority != config.admin {
+        require!(authority == config.guardian, AppError::NotAuthorized);
+        require!(
+            args.pause_flags & previous_flags == previous_flags,
+            AppError::NotAuthorized
+        );
+    }
+
+    config.pause_flags = args.pause_flags;
+
+    emit!(PauseChangedEvent {
+        authority,
+        previous_flags,
+        pause_flags: args.pause_flags,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Pause flags: {:#x} -> {:#x}", previous_flags, args.pause_flags);
+
+    Ok(())
+}
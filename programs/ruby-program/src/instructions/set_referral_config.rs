@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::ReferralConfigSetEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(args: SetReferralConfigArgs)]
+pub struct SetReferralConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+        has_one = admin @ AppError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_CONFIG],
+        bump,
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    /// Stats of the protocol referral sink (auto-created if needed)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ReferrerStats::LEN,
+        seeds = [REFERRER_STATS, args.protocol_referrer.as_ref()],
+        bump,
+    )]
+    pub protocol_referrer_stats: Account<'info, ReferrerStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetReferralConfigArgs {
+    /// Referred volume needed to reach each tier (first must be 0, ascending)
+    pub tier_thresholds: [u64; REFERRAL_TIERS],
+
+    /// Referrer rate for each tier, in basis points (non-decreasing)
+    pub tier_bps: [u64; REFERRAL_TIERS],
+
+    /// Second-level referrer rate, in basis points
+    pub second_level_bps: u64,
+
+    /// Seconds after registering in which a referee may change referrer once
+    pub change_window: i64,
+
+    /// Wallet credited with the base-rate fee of miners without a referral
+    /// (claims with claim_referrer_rewards)
+    pub protocol_referrer: Pubkey,
+}
+
+/// Handler for set_referral_config instruction
+///
+/// Tiers are recomputed lazily: a referrer moves to its new tier the next time
+/// one of its referees is checkpointed.
+pub fn handler(ctx: Context<SetReferralConfig>, args: SetReferralConfigArgs) -> Result<()> {
+    let clock = Clock::get()?;
+    let referral_config = &mut ctx.accounts.referral_config;
+
+    referral_config.tier_thresholds = args.tier_thresholds;
+    referral_config.tier_bps = args.tier_bps;
+    referral_config.second_level_bps = args.second_level_bps;
+    referral_config.change_window = args.change_window;
+    referral_config.protocol_referrer = args.protocol_referrer;
+    require!(referral_config.is_valid(), AppError::InvalidReferralConfig);
+
+    let protocol_referrer_stats = &mut ctx.accounts.protocol_referrer_stats;
+    if protocol_referrer_stats.referrer == Pubkey::default() {
+        protocol_referrer_stats.referrer = args.protocol_referrer;
+    }
+
+    emit!(ReferralConfigSetEvent {
+        tier_thresholds: args.tier_thresholds,
+        tier_bps: args.tier_bps,
+        second_level_bps: args.second_level_bps,
+        change_window: args.change_window,
+        protocol_referrer: args.protocol_referrer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
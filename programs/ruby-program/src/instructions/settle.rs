@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::SettleEvent;
+use crate::state::*;
+use crate::utils::transfer_lamports_safe;
+use super::checkpoint::{checkpoint_miner, emit_checkpoint_event, load_referral, store_referral};
+
+#[derive(Accounts)]
+pub struct Settle<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [BOARD],
+        bump,
+    )]
+    pub board: Account<'info, Board>,
+
+    #[account(
+        mut,
+        seeds = [MINER, authority.key().as_ref()],
+        bump,
+    )]
+    pub miner: Account<'info, Miner>,
+
+    /// CHECK: Round PDA, deserialized in the handler (may already be closed by close_round)
+    #[account(
+        mut,
+        seeds = [ROUND, &miner.round_id.to_le_bytes()],
+        bump,
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Referral PDA, deserialized in the handler (may not exist)
+    #[account(
+        mut,
+        seeds = [REFERRAL, authority.key().as_ref()],
+        bump,
+    )]
+    pub referral: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [REFERRAL_CONFIG],
+        bump,
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    /// Stats of the miner's referrer, or of the protocol sink without a referral
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS, referrer_stats.referrer.as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    /// Stats of the second-level referrer (required when the referral has one)
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS, second_referrer_stats.referrer.as_ref()],
+        bump,
+    )]
+    pub second_referrer_stats: Option<Account<'info, ReferrerStats>>,
+
+    #[account(address = MINT_ADDRESS)]
+    pub mint: Account<'info, Mint>,
+
+    /// Treasury's token account (source of tokens)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_tokens: Account<'info, TokenAccount>,
+
+    /// CHECK: Receives the SOL rewards; defaults to authority when omitted
+    #[account(mut)]
+    pub sol_recipient: Option<UncheckedAccount<'info>>,
+
+    /// Any RUBY token account; RUBY stays on the miner when omitted
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub recipient: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for settle instruction
+///
+/// Checkpoints the miner's last round if needed, then claims SOL and RUBY rewards
+/// in one go. SOL goes to `sol_recipient` (or the authority); RUBY goes to
+/// `recipient` when given, so no associated token account has to be created.
+pub fn handler(ctx: Context<Settle>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Clone account infos FIRST before any mutable borrows
+    let miner_info = ctx.accounts.miner.to_account_info();
+    let round_info = ctx.accounts.round.to_account_info();
+
+    // Checkpoint the last round if needed
+    let miner = &mut ctx.accounts.miner;
+    if miner.checkpoint_id != miner.round_id {
+        ctx.accounts.config.assert_not_paused(PAUSE_CHECKPOINT)?;
+
+        let mut round_state = if round_info.data_is_empty() {
+            None
+        } else {
+            require_keys_eq!(*round_info.owner, crate::ID, AppError::InvalidRound);
+            Some(Round::try_deserialize(&mut &round_info.try_borrow_data()?[..])?)
+        };
+        let referral_info = ctx.accounts.referral.to_account_info();
+        let mut referral = load_referral(&referral_info)?;
+
+        let outcome = checkpoint_miner(
+            miner,
+            &miner_info,
+            round_state.as_mut(),
+            &round_info,
+            &ctx.accounts.board,
+            &mut ctx.accounts.treasury,
+            referral.as_mut(),
+            &ctx.accounts.referral_config,
+            &mut ctx.accounts.referrer_stats,
+            ctx.accounts.second_referrer_stats.as_deref_mut(),
+            &clock,
+        )?;
+
+        if outcome.status == CheckpointStatus::Checkpointed {
+            let round = round_state.as_ref().unwrap();
+
+            // Persist round and referral changes
+            round.try_serialize(&mut *round_info.try_borrow_mut_data()?)?;
+            store_referral(referral.as_ref(), &referral_info)?;
+
+            // Bot fee goes back to the authority
+            if outcome.bot_fee > 0 {
+                **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += outcome.bot_fee;
+            }
+
+            emit_checkpoint_event(miner, round, &outcome, &clock);
+        }
+    }
+
+    // Claim SOL
+    let sol_recipient = match &ctx.accounts.sol_recipient {
+        Some(recipient) => recipient.to_account_info(),
+        None => ctx.accounts.authority.to_account_info(),
+    };
+    let mut sol_amount = 0;
+    if miner.rewards_sol > 0 {
+        ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
+
+        sol_amount = miner.claim_sol(&clock);
+        transfer_lamports_safe(&miner_info, &sol_recipient, sol_amount)?;
+        msg!("Claimed {} lamports to {}", sol_amount, sol_recipient.key());
+    }
+
+    // Claim RUBY
+    let mut token_amount = 0;
+    let mut token_recipient = Pubkey::default();
+    if let Some(recipient) = &ctx.accounts.recipient {
+        ctx.accounts.config.assert_not_paused(PAUSE_CLAIM)?;
+
+        token_recipient = recipient.key();
+        token_amount = miner.claim_token(&clock, &mut ctx.accounts.treasury);
+
+        if token_amount > 0 {
+            let treasury_bump = ctx.bumps.treasury;
+            let signer_seeds: &[&[&[u8]]] = &[&[TREASURY, &[treasury_bump]]];
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.treasury_tokens.to_account_info(),
+                to: recipient.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, token_amount)?;
+
+            msg!("Claimed {} TOKEN to {}", token_amount, token_recipient);
+        }
+    }
+
+    emit!(SettleEvent {
+        authority: ctx.accounts.authority.key(),
+        checkpoint_id: miner.checkpoint_id,
+        sol_amount,
+        sol_recipient: sol_recipient.key(),
+        token_amount,
+        token_recipient,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
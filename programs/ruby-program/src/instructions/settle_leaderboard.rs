@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::LeaderboardSettledEvent;
+use crate::state::*;
+use crate::utils::transfer_lamports;
+
+#[derive(Accounts)]
+pub struct SettleLeaderboard<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [LEADERBOARD, &leaderboard.epoch.to_le_bytes()],
+        bump,
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+}
+
+/// Handler for settle_leaderboard instruction
+///
+/// remaining_accounts:
+/// - Wallet of each ranked deployer, in leaderboard order (writable)
+///
+/// Permissionless. Pays the leaderboard's own pool (credited by the rounds that
+/// ended in its week) to the ranked deployers by `config.leaderboard_weights` once
+/// the week has ended, so settling late never touches later weeks' funds. Weight
+/// for unfilled ranks is carried into the next pool credited at reset.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SettleLeaderboard<'info>>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_CLAIM)?;
+
+    let clock = Clock::get()?;
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let treasury = &mut ctx.accounts.treasury;
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let weights = ctx.accounts.config.leaderboard_weights;
+
+    require!(!leaderboard.is_settled(), AppError::LeaderboardAlreadySettled);
+    require!(clock.slot >= leaderboard.end_slot, AppError::LeaderboardNotEnded);
+
+    let ranked: Vec<LeaderboardEntry> = leaderboard.entries
+        .iter()
+        .filter(|e| e.deployed > 0)
+        .copied()
+        .collect();
+    require!(
+        ctx.remaining_accounts.len() == ranked.len(),
+        AppError::InvalidLeaderboardRecipient
+    );
+
+    let payouts = leaderboard.settle(&weights, treasury, clock.slot)?;
+    let mut winners = Vec::with_capacity(ranked.len());
+
+    for (rank, ((entry, recipient), &payout)) in ranked
+        .iter()
+        .zip(ctx.remaining_accounts.iter())
+        .zip(payouts.iter())
+        .enumerate()
+    {
+        require!(
+            recipient.key() == entry.authority && recipient.is_writable,
+            AppError::InvalidLeaderboardRecipient
+        );
+
+        if payout > 0 {
+            transfer_lamports(&treasury_info, recipient, payout)?;
+        }
+
+        msg!("Leaderboard rank {}: {} deployed {} lamports, paid {}",
+            rank + 1, entry.authority, entry.deployed, payout);
+        winners.push(entry.authority);
+    }
+
+    emit!(LeaderboardSettledEvent {
+        epoch: leaderboard.epoch,
+        pool: leaderboard.pool,
+        total_paid: leaderboard.total_paid,
+        winners,
+        payouts,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
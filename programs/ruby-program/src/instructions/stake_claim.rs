@@ -10,6 +10,12 @@ pub struct StakeClaim<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [STAKE, signer.key().as_ref()],
@@ -29,6 +35,8 @@ pub struct StakeClaim<'info> {
 }
 
 pub fn handler(ctx: Context<StakeClaim>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_STAKE)?;
+
     let stake = &mut ctx.accounts.stake;
     let treasury = &mut ctx.accounts.treasury;
 
@@ -39,14 +47,14 @@ pub fn handler(ctx: Context<StakeClaim>) -> Result<()> {
     );
 
     // Update rewards to ensure we have latest balance
-    stake.update_rewards(treasury);
+    stake.update_rewards(&ctx.accounts.clock, treasury)?;
 
     // Claim all available rewards
     let amount = stake.claim(
         u64::MAX, // Claim maximum available (will be capped by stake.rewards)
         &ctx.accounts.clock,
         treasury
-    );
+    )?;
 
     if amount > 0 {
         // Update treasury balance BEFORE transfer to prevent reentrancy
@@ -11,6 +11,12 @@ pub struct StakeDeposit<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         init_if_needed,
         payer = signer,
@@ -48,6 +54,8 @@ pub struct StakeDeposit<'info> {
 }
 
 pub fn handler(ctx: Context<StakeDeposit>, amount: u64) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_STAKE)?;
+
     // Validate minimum stake amount
     require!(
         amount >= MIN_STAKE_AMOUNT,
@@ -62,6 +70,8 @@ pub fn handler(ctx: Context<StakeDeposit>, amount: u64) -> Result<()> {
     if stake.authority == Pubkey::default() {
         stake.authority = ctx.accounts.signer.key();
         stake.rewards_factor = treasury.stake_rewards_factor;
+        stake.rewards_updated_at = clock.unix_timestamp;
+        stake.version = STAKE_VERSION;
     }
 
     // Update rewards before changing balance
@@ -70,7 +80,7 @@ pub fn handler(ctx: Context<StakeDeposit>, amount: u64) -> Result<()> {
         clock,
         treasury,
         &ctx.accounts.sender_tokens
-    );
+    )?;
 
     // Transfer TOKEN from user to stake account
     token::transfer(
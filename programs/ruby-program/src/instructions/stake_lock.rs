@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::StakeLockedEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct StakeLock<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [STAKE, signer.key().as_ref()],
+        bump,
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(mut, seeds = [TREASURY], bump)]
+    pub treasury: Account<'info, Treasury>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StakeLockArgs {
+    /// Amount of the unlocked staked balance to lock
+    pub amount: u64,
+
+    /// Lock period (StakeLockPeriod: 0 = 1 month, 1 = 3 months, 2 = 6 months, 3 = 12 months)
+    pub period: u64,
+}
+
+/// Handler for stake_lock instruction
+///
+/// Locks part of the already-staked balance in a new position. The position
+/// earns rewards at its period's multiplier and cannot be withdrawn until it
+/// unlocks.
+pub fn handler(ctx: Context<StakeLock>, args: StakeLockArgs) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_STAKE)?;
+
+    let clock = Clock::get()?;
+    let stake = &mut ctx.accounts.stake;
+    let treasury = &mut ctx.accounts.treasury;
+
+    require!(
+        stake.authority == ctx.accounts.signer.key(),
+        AppError::NotAuthorized
+    );
+    require!(args.amount >= MIN_STAKE_AMOUNT, AppError::AmountTooSmall);
+
+    let period = StakeLockPeriod::from_u64(args.period)?;
+    let index = stake.lock(args.amount, period, &clock, treasury)?;
+    let lock = stake.locks[index];
+
+    emit!(StakeLockedEvent {
+        authority: stake.authority,
+        index: index as u64,
+        amount: lock.amount,
+        period: lock.period,
+        unlock_at: lock.unlock_at,
+        multiplier_bps: lock.multiplier_bps,
+        weighted_balance: stake.weighted_balance,
+        total_stake_weight: treasury.total_stake_weight,
+    });
+
+    msg!("Locked {} TOKEN until {} ({} bps)", lock.amount, lock.unlock_at, lock.multiplier_bps);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct StakeReleaseLocks<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [STAKE, stake.authority.as_ref()],
+        bump,
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(mut, seeds = [TREASURY], bump)]
+    pub treasury: Account<'info, Treasury>,
+}
+
+/// Handler for stake_release_locks instruction
+///
+/// Permissionless crank. Credits the stake's pending rewards, with expired lock
+/// positions boosted only up to their unlock time (the rest of their boost goes
+/// to buyback), then releases those positions so their boost stops counting
+/// toward the total stake weight.
+pub fn handler(ctx: Context<StakeReleaseLocks>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_STAKE)?;
+
+    let clock = Clock::get()?;
+    let stake = &mut ctx.accounts.stake;
+    let treasury = &mut ctx.accounts.treasury;
+
+    let locked_before = stake.locked_balance();
+    stake.update_rewards(&clock, treasury)?;
+    stake.release_expired_locks(&clock, treasury)?;
+
+    msg!("Released {} TOKEN from expired locks of {}",
+        locked_before - stake.locked_balance(), stake.authority);
+
+    Ok(())
+}
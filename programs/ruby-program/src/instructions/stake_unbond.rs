@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::StakeUnbondEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct StakeUnbond<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [STAKE, signer.key().as_ref()],
+        bump,
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(mut, seeds = [TREASURY], bump)]
+    pub treasury: Account<'info, Treasury>,
+}
+
+/// Handler for stake_unbond instruction
+///
+/// Moves unlocked stake into the unbonding queue. It stops earning rewards
+/// immediately and can be withdrawn with stake_withdraw_unbonded once the
+/// configured cooldown has passed.
+pub fn handler(ctx: Context<StakeUnbond>, amount: u64) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
+
+    let clock = Clock::get()?;
+    let cooldown = ctx.accounts.config.stake_cooldown;
+    let stake = &mut ctx.accounts.stake;
+    let treasury = &mut ctx.accounts.treasury;
+
+    require!(
+        stake.authority == ctx.accounts.signer.key(),
+        AppError::NotAuthorized
+    );
+
+    // Update rewards before changing balance
+    let (amount, index) = stake.unbond(amount, cooldown, &clock, treasury)?;
+    let available_at = stake.unbonding[index].available_at;
+
+    emit!(StakeUnbondEvent {
+        authority: stake.authority,
+        amount,
+        available_at,
+        balance: stake.balance,
+        total_staked: treasury.total_staked,
+    });
+
+    msg!("Unbonding {} TOKEN tokens, available at {}", amount, available_at);
+
+    Ok(())
+}
@@ -7,10 +7,16 @@ use crate::events::StakeWithdrawEvent;
 use crate::state::*;
 
 #[derive(Accounts)]
-pub struct StakeWithdraw<'info> {
+pub struct StakeWithdrawUnbonded<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [STAKE, signer.key().as_ref()],
@@ -18,7 +24,7 @@ pub struct StakeWithdraw<'info> {
     )]
     pub stake: Account<'info, Stake>,
 
-    #[account(mut, seeds = [TREASURY], bump)]
+    #[account(seeds = [TREASURY], bump)]
     pub treasury: Account<'info, Treasury>,
 
     #[account(address = MINT_ADDRESS)]
@@ -44,7 +50,12 @@ pub struct StakeWithdraw<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<StakeWithdraw>, amount: u64) -> Result<()> {
+/// Handler for stake_withdraw_unbonded instruction
+///
+/// Transfers out every unbonding entry whose cooldown has passed.
+pub fn handler(ctx: Context<StakeWithdrawUnbonded>) -> Result<()> {
+    ctx.accounts.config.assert_not_paused(PAUSE_WITHDRAW)?;
+
     // Validate authority
     require!(
         ctx.accounts.stake.authority == ctx.accounts.signer.key(),
@@ -58,11 +69,11 @@ pub fn handler(ctx: Context<StakeWithdraw>, amount: u64) -> Result<()> {
 
     // Now get mutable references
     let stake = &mut ctx.accounts.stake;
-    let treasury = &mut ctx.accounts.treasury;
+    let treasury = &ctx.accounts.treasury;
     let clock = &ctx.accounts.clock;
 
-    // Update rewards before changing balance
-    let amount = stake.withdraw(amount, clock, treasury);
+    let amount = stake.withdraw_unbonded(clock);
+    require!(amount > 0, AppError::StakeCooldownActive);
 
     // Transfer TOKEN from stake to user with PDA signing
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -89,7 +100,7 @@ pub fn handler(ctx: Context<StakeWithdraw>, amount: u64) -> Result<()> {
 
     // Safety check
     require!(
-        ctx.accounts.stake_tokens.amount >= stake.balance,
+        ctx.accounts.stake_tokens.amount >= stake.balance + stake.unbonding_balance(),
         AppError::InsufficientBalance
     );
 
@@ -101,7 +112,7 @@ pub fn handler(ctx: Context<StakeWithdraw>, amount: u64) -> Result<()> {
         total_staked: treasury.total_staked,
     });
 
-    msg!("Withdrew {} TOKEN tokens from stake", amount);
+    msg!("Withdrew {} unbonded TOKEN tokens from stake", amount);
 
     Ok(())
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::ExecutorUnregisteredEvent;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UnregisterExecutor<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [EXECUTOR, authority.key().as_ref()],
+        bump,
+    )]
+    pub executor: Account<'info, Executor>,
+}
+
+pub fn handler(ctx: Context<UnregisterExecutor>) -> Result<()> {
+    emit!(ExecutorUnregisteredEvent {
+        executor: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    // Executor account will be closed automatically due to close constraint
+    Ok(())
+}
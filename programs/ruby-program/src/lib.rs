@@ -29,11 +29,30 @@ pub mod ruby_program {
         instructions::deploy::handler(ctx, amount, squares)
     }
 
+    /// Deploy a different amount of SOL to each chosen square
+    pub fn deploy_weighted(ctx: Context<Deploy>, args: DeployWeightedArgs) -> Result<()> {
+        instructions::deploy_weighted::handler(ctx, args)
+    }
+
     /// Checkpoint miner rewards
     pub fn checkpoint(ctx: Context<Checkpoint>) -> Result<()> {
         instructions::checkpoint::handler(ctx)
     }
 
+    /// Checkpoint a batch of miners for one round
+    /// Pass (miner, referral, referrer_stats, second_referrer_stats) groups in remaining_accounts
+    pub fn checkpoint_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CheckpointMany<'info>>,
+        round_id: u64,
+    ) -> Result<()> {
+        instructions::checkpoint_many::handler(ctx, round_id)
+    }
+
+    /// Checkpoint if needed, then claim SOL and TOKEN rewards in one instruction
+    pub fn settle(ctx: Context<Settle>) -> Result<()> {
+        instructions::settle::handler(ctx)
+    }
+
     /// Claim RUBY token rewards
     /// Referral fee is calculated at checkpoint instruction
     pub fn claim_token(ctx: Context<ClaimToken>) -> Result<()> {
@@ -57,9 +76,14 @@ pub mod ruby_program {
         instructions::stake_deposit::handler(ctx, amount)
     }
 
-    /// Withdraw RUBY tokens from staking
-    pub fn stake_withdraw(ctx: Context<StakeWithdraw>, amount: u64) -> Result<()> {
-        instructions::stake_withdraw::handler(ctx, amount)
+    /// Start unbonding staked RUBY (withdrawable after the cooldown)
+    pub fn stake_unbond(ctx: Context<StakeUnbond>, amount: u64) -> Result<()> {
+        instructions::stake_unbond::handler(ctx, amount)
+    }
+
+    /// Withdraw RUBY whose unbonding cooldown has passed
+    pub fn stake_withdraw_unbonded(ctx: Context<StakeWithdrawUnbonded>) -> Result<()> {
+        instructions::stake_withdraw_unbonded::handler(ctx)
     }
 
     /// Claim SOL staking rewards (claims all available rewards)
@@ -67,6 +91,16 @@ pub mod ruby_program {
         instructions::stake_claim::handler(ctx)
     }
 
+    /// Lock staked TOKEN for a period to earn a reward multiplier
+    pub fn stake_lock(ctx: Context<StakeLock>, args: StakeLockArgs) -> Result<()> {
+        instructions::stake_lock::handler(ctx, args)
+    }
+
+    /// Release any stake's expired lock positions (permissionless)
+    pub fn stake_release_locks(ctx: Context<StakeReleaseLocks>) -> Result<()> {
+        instructions::stake_release_locks::handler(ctx)
+    }
+
     // ===== REFERRAL =====
 
     /// Register a referral relationship
@@ -74,7 +108,8 @@ pub mod ruby_program {
         instructions::register_referral::handler(ctx)
     }
 
-    /// Claim referral rewards from multiple referees
+    /// Claim referral rewards held on individual referees
+    /// (second-level and pre-aggregate rewards)
     /// Pass Referral PDAs in remaining_accounts
     pub fn claim_referral_rewards<'info>(
         ctx: Context<'_, '_, 'info, 'info, ClaimReferralRewards<'info>>
@@ -82,6 +117,56 @@ pub mod ruby_program {
         instructions::claim_referral_rewards::handler(ctx)
     }
 
+    /// Claim referral rewards credited to the referrer's stats account
+    pub fn claim_referrer_rewards(ctx: Context<ClaimReferrerRewards>) -> Result<()> {
+        instructions::claim_referrer_rewards::handler(ctx)
+    }
+
+    /// Create the stats account of a referrer (permissionless)
+    pub fn init_referrer_stats(ctx: Context<InitReferrerStats>) -> Result<()> {
+        instructions::init_referrer_stats::handler(ctx)
+    }
+
+    /// Change referrer once within the change window
+    pub fn change_referrer(ctx: Context<ChangeReferrer>) -> Result<()> {
+        instructions::change_referrer::handler(ctx)
+    }
+
+    // ===== LEADERBOARD =====
+
+    /// Pay out a finished weekly leaderboard
+    /// Pass ranked deployer wallets in remaining_accounts
+    pub fn settle_leaderboard<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleLeaderboard<'info>>
+    ) -> Result<()> {
+        instructions::settle_leaderboard::handler(ctx)
+    }
+
+    // ===== PRESALE =====
+
+    /// Open the presale contribution window
+    pub fn presale_open(ctx: Context<PresaleOpen>, args: PresaleOpenArgs) -> Result<()> {
+        instructions::presale_open::handler(ctx, args)
+    }
+
+    /// Contribute SOL to the presale
+    pub fn presale_contribute(ctx: Context<PresaleContribute>, amount: u64) -> Result<()> {
+        instructions::presale_contribute::handler(ctx, amount)
+    }
+
+    /// Finalize the presale (or cancel it to enable refunds)
+    pub fn presale_finalize(
+        ctx: Context<PresaleFinalize>,
+        args: PresaleFinalizeArgs,
+    ) -> Result<()> {
+        instructions::presale_finalize::handler(ctx, args)
+    }
+
+    /// Claim presale RUBY, or a SOL refund if the presale was cancelled
+    pub fn presale_claim(ctx: Context<PresaleClaim>) -> Result<()> {
+        instructions::presale_claim::handler(ctx)
+    }
+
     // ===== AUTOMATION =====
 
     /// Configure automation
@@ -94,6 +179,32 @@ pub mod ruby_program {
         instructions::cancel_automate::handler(ctx)
     }
 
+    /// Rotate the automation executor without touching its balance
+    pub fn set_automation_executor(
+        ctx: Context<SetAutomationExecutor>,
+        args: SetAutomationExecutorArgs,
+    ) -> Result<()> {
+        instructions::set_automation_executor::handler(ctx, args)
+    }
+
+    /// Register as an open-market executor or update the published fee
+    pub fn register_executor(
+        ctx: Context<RegisterExecutor>,
+        args: RegisterExecutorArgs,
+    ) -> Result<()> {
+        instructions::register_executor::handler(ctx, args)
+    }
+
+    /// Leave the executor registry
+    pub fn unregister_executor(ctx: Context<UnregisterExecutor>) -> Result<()> {
+        instructions::unregister_executor::handler(ctx)
+    }
+
+    /// Grow an automation to the current layout and bump its version
+    pub fn migrate_automation(ctx: Context<MigrateAutomation>) -> Result<()> {
+        instructions::migrate_automation::handler(ctx)
+    }
+
     // ===== ROUND MANAGEMENT =====
 
     /// Reset round and start new one
@@ -101,6 +212,11 @@ pub mod ruby_program {
         instructions::reset::handler(ctx)
     }
 
+    /// Close an expired round, sweeping unclaimed SOL and returning rent
+    pub fn close_round(ctx: Context<CloseRound>) -> Result<()> {
+        instructions::close_round::handler(ctx)
+    }
+
     // ===== ADMIN INSTRUCTIONS =====
 
     /// Buy and burn RUBY tokens
@@ -116,27 +232,37 @@ pub mod ruby_program {
         instructions::wrap::handler(ctx)
     }
 
-    /// Set admin address
-    pub fn set_admin(ctx: Context<SetAdmin>, args: SetAdminArgs) -> Result<()> {
-        instructions::set_admin::handler(ctx, args)
+    /// Propose a new admin (takes effect once accepted)
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, args: ProposeAdminArgs) -> Result<()> {
+        instructions::propose_admin::handler(ctx, args)
+    }
+
+    /// Accept a pending admin proposal
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::handler(ctx)
     }
 
-    /// Set fee collector address
-    pub fn set_fee_collector(
-        ctx: Context<SetFeeCollector>,
-        args: SetFeeCollectorArgs,
+    /// Set bury authority address
+    pub fn set_bury_authority(
+        ctx: Context<SetBuryAuthority>,
+        args: SetBuryAuthorityArgs,
     ) -> Result<()> {
-        instructions::set_fee_collector::handler(ctx, args)
+        instructions::set_bury_authority::handler(ctx, args)
+    }
+
+    /// Queue a timelocked change to the fee collector, swap program or entropy var
+    pub fn queue_change(ctx: Context<QueueChange>, args: QueueChangeArgs) -> Result<()> {
+        instructions::queue_change::handler(ctx, args)
     }
 
-    /// Set swap program
-    pub fn set_swap_program(ctx: Context<SetSwapProgram>) -> Result<()> {
-        instructions::set_swap_program::handler(ctx)
+    /// Apply a queued config change once its delay has passed
+    pub fn execute_change(ctx: Context<ExecuteChange>) -> Result<()> {
+        instructions::execute_change::handler(ctx)
     }
 
-    /// Set entropy var address
-    pub fn set_var_address(ctx: Context<SetVarAddress>) -> Result<()> {
-        instructions::set_var_address::handler(ctx)
+    /// Cancel a queued config change (admin or guardian)
+    pub fn cancel_change(ctx: Context<CancelChange>) -> Result<()> {
+        instructions::cancel_change::handler(ctx)
     }
 
     /// Create new entropy var
@@ -148,4 +274,87 @@ pub mod ruby_program {
     pub fn set_buffer(ctx: Context<SetBuffer>, args: SetBufferArgs) -> Result<()> {
         instructions::set_buffer::handler(ctx, args)
     }
+
+    /// Set leaderboard payout weights
+    pub fn set_leaderboard_weights(
+        ctx: Context<SetLeaderboardWeights>,
+        args: SetLeaderboardWeightsArgs,
+    ) -> Result<()> {
+        instructions::set_leaderboard_weights::handler(ctx, args)
+    }
+
+    /// Queue a new fee schedule for the next round
+    pub fn set_fee_schedule(
+        ctx: Context<SetFeeSchedule>,
+        args: SetFeeScheduleArgs,
+    ) -> Result<()> {
+        instructions::set_fee_schedule::handler(ctx, args)
+    }
+
+    /// Create the referral config with the default tiers (deployments that predate it)
+    pub fn init_referral_config(
+        ctx: Context<InitReferralConfig>,
+        args: InitReferralConfigArgs,
+    ) -> Result<()> {
+        instructions::init_referral_config::handler(ctx, args)
+    }
+
+    /// Set referral tiers, second-level rate and change window
+    pub fn set_referral_config(
+        ctx: Context<SetReferralConfig>,
+        args: SetReferralConfigArgs,
+    ) -> Result<()> {
+        instructions::set_referral_config::handler(ctx, args)
+    }
+
+    /// Set SOL motherlode odds (0 disables)
+    pub fn set_motherlode_sol_odds(
+        ctx: Context<SetMotherlodeSolOdds>,
+        args: SetMotherlodeSolOddsArgs,
+    ) -> Result<()> {
+        instructions::set_motherlode_sol_odds::handler(ctx, args)
+    }
+
+    /// Grow the config to the current layout and bump its version
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config::handler(ctx)
+    }
+
+    /// Grow the treasury to the current layout and bump its version
+    pub fn migrate_treasury(ctx: Context<MigrateTreasury>) -> Result<()> {
+        instructions::migrate_treasury::handler(ctx)
+    }
+
+    /// Grow the board to the current layout and bump its version
+    pub fn migrate_board(ctx: Context<MigrateBoard>) -> Result<()> {
+        instructions::migrate_board::handler(ctx)
+    }
+
+    /// Grow a round created before the layout grew
+    pub fn migrate_round(ctx: Context<MigrateRound>, args: MigrateRoundArgs) -> Result<()> {
+        instructions::migrate_round::handler(ctx, args)
+    }
+
+    /// Grow a stake account created before the layout grew
+    pub fn migrate_stake(ctx: Context<MigrateStake>, args: MigrateStakeArgs) -> Result<()> {
+        instructions::migrate_stake::handler(ctx, args)
+    }
+
+    /// Create the emission schedule with the launch curve (deployments from before it existed)
+    pub fn init_emission_schedule(ctx: Context<InitEmissionSchedule>) -> Result<()> {
+        instructions::init_emission_schedule::handler(ctx)
+    }
+
+    /// Append a future segment to the emission schedule
+    pub fn add_emission_segment(
+        ctx: Context<AddEmissionSegment>,
+        args: AddEmissionSegmentArgs,
+    ) -> Result<()> {
+        instructions::add_emission_segment::handler(ctx, args)
+    }
+
+    /// Set pause flags (admin or guardian)
+    pub fn set_pause(ctx: Context<SetPause>, args: SetPauseArgs) -> Result<()> {
+        instructions::set_pause::handler(ctx, args)
+    }
 }
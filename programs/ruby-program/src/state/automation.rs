@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
 
 #[account]
 #[derive(Default)]
@@ -22,8 +24,57 @@ pub struct Automation {
     pub strategy: u64,
 
     /// The mask of squares this automation should deploy to if preferred strategy.
-    /// If strategy is Random, first bit is used to determine how many squares to deploy to.
+    /// For the rotating strategy, the mask that is rotated each round.
     pub mask: u64,
+
+    /// The layout version of this account.
+    pub version: u64,
+
+    /// Number of squares to deploy to for the Random, LeastCrowded, Contrarian
+    /// and Momentum strategies.
+    pub square_count: u64,
+
+    /// Squares to rotate the mask by each round for the Rotating strategy.
+    pub rotation_step: u64,
+
+    /// Maximum SOL deployed per round (0 for no cap).
+    pub max_per_round: u64,
+
+    /// Maximum number of rounds to execute (0 for no limit).
+    pub max_rounds: u64,
+
+    /// Number of rounds executed since the automation was configured.
+    pub rounds_executed: u64,
+
+    /// Slot after which the automation stops executing (0 for never).
+    pub expires_at: u64,
+
+    /// Maximum net SOL loss before the automation stops (0 to disable).
+    pub stop_loss: u64,
+
+    /// SOL spent on deploys and executor fees since the automation was configured.
+    pub total_spent: u64,
+
+    /// The miner's lifetime SOL rewards when the automation was configured.
+    pub rewards_baseline: u64,
+
+    /// The last round the executor deployed in (u64::MAX if none).
+    pub last_round_executed: u64,
+
+    /// Checkpoint the miner's prior round inside the executor's deploy.
+    pub auto_checkpoint: bool,
+
+    /// Move claimable SOL winnings back into `balance` after an auto-checkpoint.
+    pub recycle_winnings: bool,
+
+    /// The lifecycle state of this automation.
+    pub status: u64,
+
+    /// Accept any registered executor whose fee is at most `max_fee`.
+    pub open_market: bool,
+
+    /// The highest registry fee the authority will pay in open-market mode.
+    pub max_fee: u64,
 }
 
 impl Automation {
@@ -34,21 +85,118 @@ impl Automation {
         32 + // executor
         8 + // fee
         8 + // strategy
-        8; // mask
+        8 + // mask
+        8 + // version
+        8 + // square_count
+        8 + // rotation_step
+        8 + // max_per_round
+        8 + // max_rounds
+        8 + // rounds_executed
+        8 + // expires_at
+        8 + // stop_loss
+        8 + // total_spent
+        8 + // rewards_baseline
+        8 + // last_round_executed
+        1 + // auto_checkpoint
+        1 + // recycle_winnings
+        8 + // status
+        1 + // open_market
+        8; // max_fee
+
+    pub fn is_active(&self) -> bool {
+        AutomationStatus::from_u64(self.status) == AutomationStatus::Active
+    }
+
+    /// Fail if the executor may not deploy for this round.
+    pub fn assert_can_execute(&self, round_id: u64, slot: u64, lifetime_rewards_sol: u64) -> Result<()> {
+        require!(
+            self.last_round_executed != round_id,
+            AppError::AutomationAlreadyExecuted
+        );
+        require!(
+            self.expires_at == 0 || slot < self.expires_at,
+            AppError::AutomationExpired
+        );
+        require!(
+            self.max_rounds == 0 || self.rounds_executed < self.max_rounds,
+            AppError::AutomationLimitReached
+        );
+        if self.stop_loss > 0 {
+            let rewards = lifetime_rewards_sol.saturating_sub(self.rewards_baseline);
+            let net_loss = self.total_spent.saturating_sub(rewards);
+            require!(net_loss < self.stop_loss, AppError::AutomationStopLoss);
+        }
+        Ok(())
+    }
+
+    /// Fill defaults for fields added since this account's version (they read as
+    /// zero after the account is grown) and bump it to AUTOMATION_VERSION. Version 2
+    /// fields are limits and flags that zero disables, so only version 0 needs work.
+    pub fn migrate(&mut self) {
+        if self.version < 1 && self.strategy == AutomationStrategy::Random as u64 {
+            // Version 0 kept the Random square count in the mask
+            self.square_count = self.mask.clamp(1, TOTAL_BOARD as u64);
+        }
+        self.version = AUTOMATION_VERSION;
+    }
+
+    /// Record a deploy by the executor.
+    pub fn record_execution(&mut self, round_id: u64, spent: u64) {
+        self.last_round_executed = round_id;
+        self.rounds_executed += 1;
+        self.total_spent = self.total_spent.saturating_add(spent);
+    }
+}
+
+/// Automation lifecycle: `automate` makes it Active; a deploy that leaves less than one
+/// round's cost refunds the balance and marks it Depleted. Closing refunds everything
+/// and deletes the account, so there is no closed state.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum AutomationStatus {
+    Active,
+    Depleted,
+}
+
+impl AutomationStatus {
+    pub fn from_u64(value: u64) -> Self {
+        match value {
+            1 => AutomationStatus::Depleted,
+            _ => AutomationStatus::Active,
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
 pub enum AutomationStrategy {
+    /// `square_count` squares picked pseudo-randomly per round.
     Random,
+    /// The fixed `mask`.
     Preferred,
+    /// The `square_count` squares with the least SOL deployed so far this round.
+    LeastCrowded,
+    /// The `square_count` squares farthest from the previous winning square.
+    Contrarian,
+    /// The previous winning square and the `square_count - 1` squares closest to it.
+    Momentum,
+    /// The `mask`, rotated by `rotation_step` squares every round.
+    Rotating,
 }
 
 impl AutomationStrategy {
-    pub fn from_u64(value: u64) -> Self {
+    pub fn from_u64(value: u64) -> Result<Self> {
         match value {
-            0 => AutomationStrategy::Random,
-            1 => AutomationStrategy::Preferred,
-            _ => AutomationStrategy::Random,
+            0 => Ok(AutomationStrategy::Random),
+            1 => Ok(AutomationStrategy::Preferred),
+            2 => Ok(AutomationStrategy::LeastCrowded),
+            3 => Ok(AutomationStrategy::Contrarian),
+            4 => Ok(AutomationStrategy::Momentum),
+            5 => Ok(AutomationStrategy::Rotating),
+            _ => err!(AppError::InvalidAutomationStrategy),
         }
     }
+
+    /// Whether this strategy picks `square_count` squares (rather than using `mask`).
+    pub fn uses_square_count(&self) -> bool {
+        !matches!(self, AutomationStrategy::Preferred | AutomationStrategy::Rotating)
+    }
 }
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::constants::{ONE_TOKEN, INITIAL_REWARD_PER_ROUND, MIN_REWARD_PER_ROUND, ONE_DAY_SLOTS};
+use crate::constants::{BOARD_VERSION, MOTHERLODE_SOL_ODDS, ONE_WEEK_SLOTS};
 
 #[account]
 #[derive(Default)]
@@ -24,6 +24,24 @@ pub struct Board {
 
     /// Total RUBY minted so far.
     pub total_minted: u64,
+
+    /// SOL motherlode trigger odds (1 in N rounds, 0 disables).
+    pub motherlode_sol_odds: u64,
+
+    /// Number of times the SOL motherlode has paid out.
+    pub motherlode_sol_hits: u64,
+
+    /// Total SOL paid out by the SOL motherlode.
+    pub motherlode_sol_paid: u64,
+
+    /// The round in which the SOL motherlode last paid out.
+    pub last_motherlode_sol_round: u64,
+
+    /// The winning square of the last reset round (u64::MAX if none).
+    pub last_winning_square: u64,
+
+    /// The layout version of this account.
+    pub version: u64,
 }
 
 impl Board {
@@ -34,41 +52,26 @@ impl Board {
         8 + // mining_start_slot
         8 + // motherlode_ruby
         8 + // mini_motherlode_sol
-        8; // total_minted
-
-    /// Calculate current reward per round based on emission schedule.
-    /// Days 0-3: 20 RUBY
-    /// After Day 3: decreases by 2 RUBY per day until reaching 6 RUBY
-    /// 2 months after hitting 6 RUBY: decreases by 1 RUBY per month
-    /// Minimum: 1 RUBY
-    pub fn current_reward(&self, current_slot: u64) -> u64 {
-        if self.mining_start_slot == 0 {
-            return INITIAL_REWARD_PER_ROUND;
-        }
+        8 + // total_minted
+        8 + // motherlode_sol_odds
+        8 + // motherlode_sol_hits
+        8 + // motherlode_sol_paid
+        8 + // last_motherlode_sol_round
+        8 + // last_winning_square
+        8; // version
 
-        let slots_elapsed = current_slot.saturating_sub(self.mining_start_slot);
-        let days_elapsed = slots_elapsed / ONE_DAY_SLOTS;
-
-        if days_elapsed <= 3 {
-            // Days 0-3: 20 RUBY
-            INITIAL_REWARD_PER_ROUND
-        } else if days_elapsed <= 10 {
-            // Days 4-10: decrease by 2 RUBY per day (20 -> 6)
-            // Day 4: 18, Day 5: 16, Day 6: 14, Day 7: 12, Day 8: 10, Day 9: 8, Day 10: 6
-            let reduction = (days_elapsed - 3) * 2 * ONE_TOKEN;
-            INITIAL_REWARD_PER_ROUND.saturating_sub(reduction).max(6 * ONE_TOKEN)
-        } else {
-            // After reaching 6 RUBY, wait 2 months then decrease by 1 RUBY per month
-            let days_at_6 = days_elapsed - 10;
-            if days_at_6 <= 60 {
-                // First 2 months at 6 RUBY
-                6 * ONE_TOKEN
-            } else {
-                // After 2 months: decrease by 1 RUBY per month (30 days)
-                let months_past = (days_at_6 - 60) / 30;
-                let reward = (6 * ONE_TOKEN).saturating_sub(months_past * ONE_TOKEN);
-                reward.max(MIN_REWARD_PER_ROUND)
-            }
+    /// Fill defaults for fields added since this account's version (they read as
+    /// zero after the account is grown) and bump it to BOARD_VERSION.
+    pub fn migrate(&mut self) {
+        if self.version < 1 {
+            self.motherlode_sol_odds = MOTHERLODE_SOL_ODDS;
+            self.last_winning_square = u64::MAX;
         }
+        self.version = BOARD_VERSION;
+    }
+
+    /// The leaderboard epoch (week index since mining started) for a slot.
+    pub fn epoch(&self, current_slot: u64) -> u64 {
+        current_slot.saturating_sub(self.mining_start_slot) / ONE_WEEK_SLOTS
     }
 }
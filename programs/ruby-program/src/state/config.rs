@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AppError;
 
 #[account]
 #[derive(Default)]
@@ -20,6 +22,33 @@ pub struct Config {
 
     /// Buffer array
     pub buffer: u64,
+
+    /// Leaderboard payout weights by rank, in basis points.
+    pub leaderboard_weights: [u64; LEADERBOARD_SIZE],
+
+    /// The layout version of this account.
+    pub version: u64,
+
+    /// The fee schedule in force.
+    pub fee_schedule: FeeSchedule,
+
+    /// The fee schedule queued by set_fee_schedule.
+    pub next_fee_schedule: FeeSchedule,
+
+    /// The round from which next_fee_schedule applies (0 if none queued).
+    pub next_fee_schedule_round: u64,
+
+    /// The address that can pause the program (in addition to the admin).
+    pub guardian: Pubkey,
+
+    /// Bitmask of paused subsystems (see PAUSE_* constants).
+    pub pause_flags: u64,
+
+    /// The proposed admin, which must call accept_admin to take over.
+    pub pending_admin: Pubkey,
+
+    /// Seconds unbonding stake waits before it can be withdrawn.
+    pub stake_cooldown: i64,
 }
 
 impl Config {
@@ -29,5 +58,257 @@ impl Config {
         32 + // fee_collector
         32 + // swap_program
         32 + // var_address
-        8; // buffer
+        8 + // buffer
+        (8 * LEADERBOARD_SIZE) + // leaderboard_weights
+        8 + // version
+        FeeSchedule::LEN + // fee_schedule
+        FeeSchedule::LEN + // next_fee_schedule
+        8 + // next_fee_schedule_round
+        32 + // guardian
+        8 + // pause_flags
+        32 + // pending_admin
+        8; // stake_cooldown
+
+    /// Fill defaults for fields added since this account's version (they read as
+    /// zero after the account is grown) and bump it to CONFIG_VERSION.
+    pub fn migrate(&mut self) {
+        if self.leaderboard_weights == [0; LEADERBOARD_SIZE] {
+            self.leaderboard_weights = LEADERBOARD_WEIGHTS_BPS;
+        }
+        if self.version < 1 {
+            self.fee_schedule = FeeSchedule::DEFAULT;
+            self.next_fee_schedule = FeeSchedule::DEFAULT;
+            self.next_fee_schedule_round = 0;
+        }
+        if self.guardian == Pubkey::default() {
+            self.guardian = self.admin;
+        }
+        if self.stake_cooldown == 0 {
+            self.stake_cooldown = STAKE_COOLDOWN;
+        }
+        self.version = CONFIG_VERSION;
+    }
+
+    /// Fail if any of the given subsystems is paused.
+    pub fn assert_not_paused(&self, flags: u64) -> Result<()> {
+        require!(self.pause_flags & flags == 0, AppError::ProgramPaused);
+        Ok(())
+    }
+
+    /// The fee schedule that applies to a round.
+    pub fn fee_schedule_at(&self, round_id: u64) -> FeeSchedule {
+        if self.next_fee_schedule_round > 0 && round_id >= self.next_fee_schedule_round {
+            self.next_fee_schedule
+        } else {
+            self.fee_schedule
+        }
+    }
+
+    /// Current value of a timelocked field, or None if the field is not timelocked.
+    pub fn timelocked_value(&self, field: ConfigField) -> Option<ConfigValue> {
+        match field {
+            ConfigField::Guardian => Some(ConfigValue::Pubkey(self.guardian)),
+            ConfigField::FeeCollector => Some(ConfigValue::Pubkey(self.fee_collector)),
+            ConfigField::SwapProgram => Some(ConfigValue::Pubkey(self.swap_program)),
+            ConfigField::VarAddress => Some(ConfigValue::Pubkey(self.var_address)),
+            ConfigField::StakeCooldown => Some(ConfigValue::U64(self.stake_cooldown as u64)),
+            _ => None,
+        }
+    }
+
+    /// Fail unless `value` can be queued for a timelocked field.
+    pub fn validate_timelocked_value(field: ConfigField, value: &ConfigValue) -> Result<()> {
+        match (field, value) {
+            (
+                ConfigField::Guardian
+                | ConfigField::FeeCollector
+                | ConfigField::SwapProgram
+                | ConfigField::VarAddress,
+                ConfigValue::Pubkey(_),
+            ) => Ok(()),
+            (ConfigField::StakeCooldown, ConfigValue::U64(cooldown)) => {
+                require!(
+                    (MIN_STAKE_COOLDOWN as u64..=MAX_STAKE_COOLDOWN as u64).contains(cooldown),
+                    AppError::InvalidStakeCooldown
+                );
+                Ok(())
+            }
+            _ => err!(AppError::InvalidConfigChange),
+        }
+    }
+
+    /// Apply a timelocked change.
+    pub fn set_timelocked_value(&mut self, field: ConfigField, value: ConfigValue) -> Result<()> {
+        Self::validate_timelocked_value(field, &value)?;
+        match (field, value) {
+            (ConfigField::Guardian, ConfigValue::Pubkey(key)) => self.guardian = key,
+            (ConfigField::FeeCollector, ConfigValue::Pubkey(key)) => self.fee_collector = key,
+            (ConfigField::SwapProgram, ConfigValue::Pubkey(key)) => self.swap_program = key,
+            (ConfigField::VarAddress, ConfigValue::Pubkey(key)) => self.var_address = key,
+            (ConfigField::StakeCooldown, ConfigValue::U64(cooldown)) => {
+                self.stake_cooldown = cooldown as i64;
+            }
+            _ => return err!(AppError::InvalidConfigChange),
+        }
+        Ok(())
+    }
+}
+
+/// Config fields reported by ConfigChangedEvent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ConfigField {
+    Admin,
+    PendingAdmin,
+    BuryAuthority,
+    Guardian,
+    FeeCollector,
+    SwapProgram,
+    VarAddress,
+    MotherlodeSolOdds,
+    StakeCooldown,
+}
+
+/// The value of a config field, typed by kind
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ConfigValue {
+    Pubkey(Pubkey),
+    U64(u64),
+}
+
+impl ConfigValue {
+    /// Borsh size of the largest variant.
+    pub const LEN: usize = 1 + 32;
+}
+
+/// Split of losing-square SOL, in basis points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct FeeSchedule {
+    /// Share to miners on the winning square.
+    pub winners_bps: u64,
+
+    /// Share for buyback and burn.
+    pub buyback_bps: u64,
+
+    /// Share to the strategic reserve (leaderboard + mini-motherlode).
+    pub reserve_bps: u64,
+
+    /// Share to the SOL motherlode pool.
+    pub motherlode_bps: u64,
+
+    /// Share to stakers (falls back to buyback while nothing is staked).
+    pub stake_bps: u64,
+
+    /// Platform fee, as a share of the winners amount.
+    pub platform_fee_bps: u64,
+}
+
+impl FeeSchedule {
+    pub const LEN: usize = 8 * 6;
+
+    pub const DEFAULT: FeeSchedule = FeeSchedule {
+        winners_bps: WINNERS_BPS,
+        buyback_bps: BUYBACK_BPS,
+        reserve_bps: RESERVE_BPS,
+        motherlode_bps: MOTHERLODE_BPS,
+        stake_bps: STAKE_BPS,
+        platform_fee_bps: PLATFORM_FEE_BPS,
+    };
+
+    /// The splits must sum to 100% and the platform fee cannot exceed 100%.
+    pub fn is_valid(&self) -> bool {
+        let total = [
+            self.winners_bps,
+            self.buyback_bps,
+            self.reserve_bps,
+            self.motherlode_bps,
+            self.stake_bps,
+        ]
+        .iter()
+        .try_fold(0u64, |acc, &bps| acc.checked_add(bps));
+        total == Some(10_000) && self.platform_fee_bps <= 10_000
+    }
+
+    /// Apply a basis point share to an amount.
+    pub fn share(amount: u64, bps: u64) -> u64 {
+        ((amount as u128 * bps as u128) / 10_000) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_fee_schedule_is_valid() {
+        assert!(FeeSchedule::DEFAULT.is_valid());
+    }
+
+    #[test]
+    fn fee_schedule_must_sum_to_100_percent() {
+        let mut fees = FeeSchedule::DEFAULT;
+        fees.winners_bps -= 100;
+        assert!(!fees.is_valid());
+
+        fees.stake_bps += 100;
+        assert!(fees.is_valid());
+
+        fees.stake_bps += 1;
+        assert!(!fees.is_valid());
+    }
+
+    #[test]
+    fn fee_schedule_rejects_overflowing_splits() {
+        let fees = FeeSchedule {
+            winners_bps: u64::MAX,
+            buyback_bps: 10_001,
+            ..FeeSchedule::DEFAULT
+        };
+        assert!(!fees.is_valid());
+    }
+
+    #[test]
+    fn fee_schedule_caps_platform_fee() {
+        let mut fees = FeeSchedule::DEFAULT;
+        fees.platform_fee_bps = 10_000;
+        assert!(fees.is_valid());
+        fees.platform_fee_bps = 10_001;
+        assert!(!fees.is_valid());
+    }
+
+    #[test]
+    fn timelocked_value_must_match_field_kind() {
+        let key = ConfigValue::Pubkey(Pubkey::new_unique());
+        let cooldown = ConfigValue::U64(MIN_STAKE_COOLDOWN as u64);
+
+        assert!(Config::validate_timelocked_value(ConfigField::Guardian, &key).is_ok());
+        assert!(Config::validate_timelocked_value(ConfigField::Guardian, &cooldown).is_err());
+        assert!(Config::validate_timelocked_value(ConfigField::StakeCooldown, &cooldown).is_ok());
+        assert!(Config::validate_timelocked_value(ConfigField::StakeCooldown, &key).is_err());
+        assert!(Config::validate_timelocked_value(ConfigField::Admin, &key).is_err());
+    }
+
+    #[test]
+    fn set_timelocked_value_applies_typed_values() {
+        let mut config = Config::default();
+        let guardian = Pubkey::new_unique();
+
+        config.set_timelocked_value(ConfigField::Guardian, ConfigValue::Pubkey(guardian)).unwrap();
+        assert_eq!(config.guardian, guardian);
+        assert_eq!(config.timelocked_value(ConfigField::Guardian), Some(ConfigValue::Pubkey(guardian)));
+
+        let cooldown = MAX_STAKE_COOLDOWN as u64;
+        config.set_timelocked_value(ConfigField::StakeCooldown, ConfigValue::U64(cooldown)).unwrap();
+        assert_eq!(config.stake_cooldown, MAX_STAKE_COOLDOWN);
+        assert!(config
+            .set_timelocked_value(ConfigField::StakeCooldown, ConfigValue::U64(cooldown + 1))
+            .is_err());
+    }
+
+    #[test]
+    fn share_rounds_down_without_overflow() {
+        assert_eq!(FeeSchedule::share(1_000, 800), 80);
+        assert_eq!(FeeSchedule::share(999, 1), 0);
+        assert_eq!(FeeSchedule::share(u64::MAX, 10_000), u64::MAX);
+        assert_eq!(FeeSchedule::share(u64::MAX, 5_000), u64::MAX / 2);
+    }
 }
@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Emission schedule - the RUBY reward curve, as append-only segments
+#[account]
+pub struct EmissionSchedule {
+    /// The number of segments in use.
+    pub len: u64,
+
+    /// The segments, ordered by start_slot_offset.
+    pub segments: [EmissionSegment; MAX_EMISSION_SEGMENTS],
+}
+
+/// A piece of the emission curve. From `start_slot_offset` (relative to
+/// `Board::mining_start_slot`) the reward starts at `reward` and drops by `step`
+/// every `step_interval` slots, never going below `floor`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct EmissionSegment {
+    /// Slots after mining start at which this segment takes effect.
+    pub start_slot_offset: u64,
+
+    /// Reward per round at the start of this segment.
+    pub reward: u64,
+
+    /// Amount the reward decreases each interval.
+    pub step: u64,
+
+    /// Number of slots between decreases (0 for a flat segment).
+    pub step_interval: u64,
+
+    /// Minimum reward per round within this segment.
+    pub floor: u64,
+}
+
+impl Default for EmissionSchedule {
+    fn default() -> Self {
+        EmissionSchedule {
+            len: 0,
+            segments: [EmissionSegment::default(); MAX_EMISSION_SEGMENTS],
+        }
+    }
+}
+
+impl EmissionSchedule {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // len
+        (EmissionSegment::LEN * MAX_EMISSION_SEGMENTS); // segments
+
+    /// The launch curve:
+    /// Days 0-3: 20 RUBY
+    /// Days 4-10: decreases by 2 RUBY per day until reaching 6 RUBY
+    /// 2 months at 6 RUBY, then decreases by 1 RUBY per month
+    /// Minimum: 1 RUBY
+    pub const DEFAULT_SEGMENTS: [EmissionSegment; 3] = [
+        EmissionSegment {
+            start_slot_offset: 0,
+            reward: INITIAL_REWARD_PER_ROUND,
+            step: 0,
+            step_interval: 0,
+            floor: INITIAL_REWARD_PER_ROUND,
+        },
+        EmissionSegment {
+            start_slot_offset: 4 * ONE_DAY_SLOTS,
+            reward: 18 * ONE_TOKEN,
+            step: 2 * ONE_TOKEN,
+            step_interval: ONE_DAY_SLOTS,
+            floor: 6 * ONE_TOKEN,
+        },
+        EmissionSegment {
+            start_slot_offset: 70 * ONE_DAY_SLOTS,
+            reward: 6 * ONE_TOKEN,
+            step: ONE_TOKEN,
+            step_interval: 30 * ONE_DAY_SLOTS,
+            floor: MIN_REWARD_PER_ROUND,
+        },
+    ];
+
+    pub fn segments(&self) -> &[EmissionSegment] {
+        &self.segments[..self.len as usize]
+    }
+
+    pub fn push(&mut self, segment: EmissionSegment) {
+        self.segments[self.len as usize] = segment;
+        self.len += 1;
+    }
+
+    /// Start a new schedule with the launch curve.
+    pub fn init_default(&mut self) {
+        self.len = 0;
+        for segment in EmissionSchedule::DEFAULT_SEGMENTS {
+            self.push(segment);
+        }
+    }
+
+    /// Calculate the reward per round at a slot.
+    pub fn reward_at(&self, mining_start_slot: u64, current_slot: u64) -> u64 {
+        let offset = current_slot.saturating_sub(mining_start_slot);
+        self.segments()
+            .iter()
+            .rev()
+            .find(|s| s.start_slot_offset <= offset)
+            .map(|s| s.reward_at(offset))
+            .unwrap_or(INITIAL_REWARD_PER_ROUND)
+    }
+}
+
+impl EmissionSegment {
+    pub const LEN: usize = 8 * 5;
+
+    pub fn reward_at(&self, offset: u64) -> u64 {
+        if self.step == 0 || self.step_interval == 0 {
+            return self.reward;
+        }
+        let steps = offset.saturating_sub(self.start_slot_offset) / self.step_interval;
+        self.reward
+            .saturating_sub(steps.saturating_mul(self.step))
+            .max(self.floor)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.reward >= self.floor && (self.step == 0 || self.step_interval > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINING_START: u64 = 1_000;
+
+    /// The hard-coded curve that EmissionSchedule::DEFAULT_SEGMENTS replaced.
+    fn legacy_current_reward(current_slot: u64) -> u64 {
+        let days_elapsed = current_slot.saturating_sub(MINING_START) / ONE_DAY_SLOTS;
+        if days_elapsed <= 3 {
+            INITIAL_REWARD_PER_ROUND
+        } else if days_elapsed <= 10 {
+            let reduction = (days_elapsed - 3) * 2 * ONE_TOKEN;
+            INITIAL_REWARD_PER_ROUND.saturating_sub(reduction).max(6 * ONE_TOKEN)
+        } else {
+            let days_at_6 = days_elapsed - 10;
+            if days_at_6 <= 60 {
+                6 * ONE_TOKEN
+            } else {
+                let months_past = (days_at_6 - 60) / 30;
+                (6 * ONE_TOKEN)
+                    .saturating_sub(months_past * ONE_TOKEN)
+                    .max(MIN_REWARD_PER_ROUND)
+            }
+        }
+    }
+
+    fn default_schedule() -> EmissionSchedule {
+        let mut schedule = EmissionSchedule::default();
+        schedule.init_default();
+        schedule
+    }
+
+    #[test]
+    fn default_segments_match_legacy_curve() {
+        let schedule = default_schedule();
+        // Every quarter day for two years, plus the slots either side of each boundary
+        let mut slot = MINING_START;
+        while slot < MINING_START + 730 * ONE_DAY_SLOTS {
+            for s in [slot.saturating_sub(1), slot, slot + 1] {
+                assert_eq!(
+                    schedule.reward_at(MINING_START, s),
+                    legacy_current_reward(s),
+                    "slot offset {}",
+                    s.saturating_sub(MINING_START)
+                );
+            }
+            slot += ONE_DAY_SLOTS / 4;
+        }
+    }
+
+    #[test]
+    fn reward_never_drops_below_floor() {
+        let schedule = default_schedule();
+        assert_eq!(schedule.reward_at(MINING_START, u64::MAX), MIN_REWARD_PER_ROUND);
+    }
+
+    #[test]
+    fn empty_schedule_pays_initial_reward() {
+        let schedule = EmissionSchedule::default();
+        assert_eq!(schedule.reward_at(MINING_START, MINING_START), INITIAL_REWARD_PER_ROUND);
+    }
+
+    #[test]
+    fn appended_segment_takes_over() {
+        let mut schedule = default_schedule();
+        schedule.push(EmissionSegment {
+            start_slot_offset: 365 * ONE_DAY_SLOTS,
+            reward: 3 * ONE_TOKEN,
+            step: 0,
+            step_interval: 0,
+            floor: 3 * ONE_TOKEN,
+        });
+        let before = MINING_START + 365 * ONE_DAY_SLOTS - 1;
+        assert_eq!(schedule.reward_at(MINING_START, before), legacy_current_reward(before));
+        assert_eq!(schedule.reward_at(MINING_START, before + 1), 3 * ONE_TOKEN);
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Executor registry entry - an operator offering automation at a published fee
+#[account]
+#[derive(Default)]
+pub struct Executor {
+    /// The operator's signing key.
+    pub authority: Pubkey,
+
+    /// The fee charged per deploy, in lamports.
+    pub fee: u64,
+
+    /// Timestamp when the executor registered.
+    pub registered_at: i64,
+
+    /// Number of open-market deploys executed.
+    pub rounds_executed: u64,
+
+    /// Total fees earned from open-market deploys.
+    pub total_fees: u64,
+}
+
+impl Executor {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        8 + // fee
+        8 + // registered_at
+        8 + // rounds_executed
+        8; // total_fees
+}
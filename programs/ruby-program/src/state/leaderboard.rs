@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use crate::constants::{LEADERBOARD_SIZE, ONE_WEEK_SLOTS};
+use crate::errors::AppError;
+use super::Treasury;
+
+/// Weekly deployer leaderboard, one per epoch
+#[account]
+#[derive(Default)]
+pub struct Leaderboard {
+    /// The epoch (week index since mining started).
+    pub epoch: u64,
+
+    /// The slot at which this week starts.
+    pub start_slot: u64,
+
+    /// The slot at which this week ends.
+    pub end_slot: u64,
+
+    /// The top deployers this week, sorted by SOL deployed (descending).
+    pub entries: [LeaderboardEntry; LEADERBOARD_SIZE],
+
+    /// The total amount of SOL deployed by all miners this week.
+    pub total_deployed: u64,
+
+    /// The amount of SOL paid out when this leaderboard was settled.
+    pub total_paid: u64,
+
+    /// The slot at which this leaderboard was settled (0 if unsettled).
+    pub settled_at: u64,
+
+    /// The SOL in this week's payout pool, credited by the rounds that ended in it.
+    pub pool: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct LeaderboardEntry {
+    /// The miner authority.
+    pub authority: Pubkey,
+
+    /// The amount of SOL deployed this week.
+    pub deployed: u64,
+}
+
+impl Leaderboard {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // epoch
+        8 + // start_slot
+        8 + // end_slot
+        ((32 + 8) * LEADERBOARD_SIZE) + // entries
+        8 + // total_deployed
+        8 + // total_paid
+        8 + // settled_at
+        8; // pool
+
+    /// Set the epoch and week bounds of a newly created leaderboard.
+    pub fn open(&mut self, epoch: u64, mining_start_slot: u64) {
+        if self.end_slot == 0 {
+            self.epoch = epoch;
+            self.start_slot = mining_start_slot + epoch * ONE_WEEK_SLOTS;
+            self.end_slot = self.start_slot + ONE_WEEK_SLOTS;
+        }
+    }
+
+    /// Record an authority's weekly total, keeping the table sorted and bounded.
+    pub fn record(&mut self, authority: Pubkey, deployed: u64) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.authority == authority) {
+            entry.deployed = deployed;
+        } else {
+            // Replace the lowest ranked entry if this authority now ranks above it.
+            let last = LEADERBOARD_SIZE - 1;
+            if deployed <= self.entries[last].deployed {
+                return;
+            }
+            self.entries[last] = LeaderboardEntry { authority, deployed };
+        }
+        self.entries.sort_by(|a, b| b.deployed.cmp(&a.deployed));
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.settled_at > 0
+    }
+
+    /// Add SOL to this week's pool, along with any SOL carried from earlier weeks.
+    /// A round reset after its week was settled carries its share to the next pool.
+    pub fn credit(&mut self, amount: u64, treasury: &mut Treasury) {
+        treasury.leaderboard_bl += amount;
+        if self.is_settled() {
+            treasury.leaderboard_carry += amount;
+        } else {
+            self.pool += amount + std::mem::take(&mut treasury.leaderboard_carry);
+        }
+    }
+
+    /// Split this week's pool between the ranked deployers by `weights`, returning
+    /// the payout for each ranked entry. The paid amount leaves
+    /// `treasury.leaderboard_bl`, and the share of unfilled ranks is carried into
+    /// the next pool credited at reset.
+    pub fn settle(
+        &mut self,
+        weights: &[u64; LEADERBOARD_SIZE],
+        treasury: &mut Treasury,
+        slot: u64,
+    ) -> Result<Vec<u64>> {
+        let mut total_paid = 0u64;
+        let mut payouts = Vec::with_capacity(LEADERBOARD_SIZE);
+        for (entry, &weight) in self.entries.iter().zip(weights.iter()) {
+            if entry.deployed == 0 {
+                break;
+            }
+            let payout = ((self.pool as u128 * weight as u128) / 10_000) as u64;
+            total_paid = total_paid.checked_add(payout).ok_or(AppError::Overflow)?;
+            payouts.push(payout);
+        }
+
+        let unpaid = self.pool.checked_sub(total_paid).ok_or(AppError::Underflow)?;
+        treasury.leaderboard_bl = treasury.leaderboard_bl
+            .checked_sub(total_paid)
+            .ok_or(AppError::Underflow)?;
+        treasury.leaderboard_carry = treasury.leaderboard_carry
+            .checked_add(unpaid)
+            .ok_or(AppError::Overflow)?;
+        self.total_paid = total_paid;
+        self.settled_at = slot;
+        Ok(payouts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::LEADERBOARD_WEIGHTS_BPS;
+
+    fn leaderboard(epoch: u64, deployers: usize, pool: u64) -> Leaderboard {
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.open(epoch, 0);
+        for i in 0..deployers {
+            leaderboard.record(Pubkey::new_unique(), 1_000 - i as u64);
+        }
+        leaderboard.pool = pool;
+        leaderboard
+    }
+
+    #[test]
+    fn epochs_settled_out_of_order_pay_their_own_pools() {
+        let week_1 = 1_000_000;
+        let week_2 = 3_000_000;
+        let mut treasury = Treasury {
+            leaderboard_bl: week_1 + week_2,
+            ..Default::default()
+        };
+        let mut first = leaderboard(1, LEADERBOARD_SIZE, week_1);
+        let mut second = leaderboard(2, LEADERBOARD_SIZE, week_2);
+
+        // Week 2 settles first; it must not take week 1's funds
+        let second_payouts = second.settle(&LEADERBOARD_WEIGHTS_BPS, &mut treasury, 10).unwrap();
+        assert_eq!(second.total_paid, week_2);
+        assert_eq!(treasury.leaderboard_bl, week_1);
+
+        // Week 1 still pays from its own pool, not from what is left in the treasury
+        let first_payouts = first.settle(&LEADERBOARD_WEIGHTS_BPS, &mut treasury, 11).unwrap();
+        assert_eq!(first.total_paid, week_1);
+        assert_eq!(treasury.leaderboard_bl, 0);
+        assert_eq!(treasury.leaderboard_carry, 0);
+
+        for rank in 0..LEADERBOARD_SIZE {
+            assert_eq!(first_payouts[rank] * 3, second_payouts[rank]);
+        }
+    }
+
+    #[test]
+    fn unfilled_ranks_are_carried_to_the_next_pool() {
+        let pool = 1_000_000;
+        let mut treasury = Treasury::default();
+        let mut first = leaderboard(1, 1, 0);
+        first.credit(pool, &mut treasury);
+
+        let payouts = first.settle(&LEADERBOARD_WEIGHTS_BPS, &mut treasury, 10).unwrap();
+        let paid = pool * LEADERBOARD_WEIGHTS_BPS[0] / 10_000;
+        assert_eq!(payouts, vec![paid]);
+        assert_eq!(treasury.leaderboard_bl, pool - paid);
+        assert_eq!(treasury.leaderboard_carry, pool - paid);
+
+        let mut second = leaderboard(2, 1, 0);
+        second.credit(500, &mut treasury);
+        assert_eq!(second.pool, pool - paid + 500);
+        assert_eq!(treasury.leaderboard_carry, 0);
+        assert_eq!(treasury.leaderboard_bl, second.pool);
+    }
+
+    #[test]
+    fn late_credit_to_a_settled_week_is_carried() {
+        let mut treasury = Treasury::default();
+        let mut first = leaderboard(1, 1, 0);
+        first.settle(&LEADERBOARD_WEIGHTS_BPS, &mut treasury, 10).unwrap();
+
+        first.credit(700, &mut treasury);
+        assert_eq!(first.pool, 0);
+        assert_eq!(treasury.leaderboard_carry, 700);
+        assert_eq!(treasury.leaderboard_bl, 700);
+    }
+}
@@ -1,5 +1,5 @@
 use super::Treasury;
-use crate::{constants::TOTAL_BOARD, utils::Numeric};
+use crate::{constants::TOTAL_BOARD, errors::AppError, utils::Numeric};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -46,6 +46,18 @@ pub struct Miner {
 
     /// The total amount of ORE this miner has mined across all blocks.
     pub lifetime_rewards_token: u64,
+
+    /// The cumulative amount of SOL on each square prior to this miner's top-up segment.
+    pub top_up_cumulative: [u64; 25],
+
+    /// The SOL in this miner's top-up segment on each square (included in `deployed`).
+    pub top_up_deployed: [u64; 25],
+
+    /// The leaderboard epoch of `leaderboard_deployed`.
+    pub leaderboard_epoch: u64,
+
+    /// The amount of SOL this miner has deployed in `leaderboard_epoch`.
+    pub leaderboard_deployed: u64,
 }
 
 impl Miner {
@@ -63,7 +75,11 @@ impl Miner {
         8 + // refined_ore
         8 + // round_id
         8 + // lifetime_rewards_sol
-        8; // lifetime_rewards_ore
+        8 + // lifetime_rewards_ore
+        (8 * TOTAL_BOARD) + // top_up_cumulative
+        (8 * TOTAL_BOARD) + // top_up_deployed
+        8 + // leaderboard_epoch
+        8; // leaderboard_deployed
 
     pub fn claim_token(&mut self, clock: &Clock, treasury: &mut Treasury) -> u64 {
         self.update_rewards(treasury);
@@ -88,6 +104,61 @@ impl Miner {
         amount
     }
 
+    /// Add SOL to a square in the current round, keeping the ticket ranges used for
+    /// top-miner sampling. The first deploy opens a segment at the square's current
+    /// total; a top-up extends the last segment if nobody deployed in between, and
+    /// otherwise opens the single top-up segment. Returns true on a first deploy.
+    pub fn deploy_square(&mut self, square: usize, round_deployed: u64, amount: u64) -> Result<bool> {
+        let is_first = self.deployed[square] == 0;
+        if is_first {
+            self.cumulative[square] = round_deployed;
+        } else if self.top_up_deployed[square] > 0 {
+            require!(
+                self.top_up_cumulative[square] + self.top_up_deployed[square] == round_deployed,
+                AppError::TopUpLimitReached
+            );
+            self.top_up_deployed[square] += amount;
+        } else {
+            let first_len = self.deployed[square];
+            if self.cumulative[square] + first_len != round_deployed {
+                self.top_up_cumulative[square] = round_deployed;
+                self.top_up_deployed[square] = amount;
+            }
+        }
+        self.deployed[square] += amount;
+        Ok(is_first)
+    }
+
+    /// Whether a missing round account for `round_id` forfeits this miner's rewards.
+    /// Only the miner's own, unsettled round can be forfeited, and only once the
+    /// board has moved past it (a closed round, not one that was never created).
+    pub fn can_forfeit(&self, round_id: u64, board_round_id: u64) -> bool {
+        round_id == self.round_id
+            && self.checkpoint_id != self.round_id
+            && round_id < board_round_id
+    }
+
+    /// Whether a top-miner sample falls in one of this miner's ticket ranges.
+    pub fn owns_ticket(&self, square: usize, sample: u64) -> bool {
+        let first_len = self.deployed[square] - self.top_up_deployed[square];
+        let in_first = sample >= self.cumulative[square]
+            && sample < self.cumulative[square] + first_len;
+        let in_top_up = self.top_up_deployed[square] > 0
+            && sample >= self.top_up_cumulative[square]
+            && sample < self.top_up_cumulative[square] + self.top_up_deployed[square];
+        in_first || in_top_up
+    }
+
+    /// Add SOL deployed to this miner's weekly total, resetting it on a new epoch.
+    pub fn record_leaderboard(&mut self, epoch: u64, amount: u64) -> u64 {
+        if self.leaderboard_epoch != epoch {
+            self.leaderboard_epoch = epoch;
+            self.leaderboard_deployed = 0;
+        }
+        self.leaderboard_deployed += amount;
+        self.leaderboard_deployed
+    }
+
     pub fn claim_sol(&mut self, clock: &Clock) -> u64 {
         let amount = self.rewards_sol;
         self.rewards_sol = 0;
@@ -111,3 +182,116 @@ impl Miner {
         self.rewards_factor = treasury.miner_rewards_factor;
     }
 }
+
+/// Result of checkpointing a miner, reported by CheckpointManyEvent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum CheckpointStatus {
+    /// Rewards were credited.
+    Checkpointed,
+    /// The miner had already checkpointed its round.
+    AlreadyCheckpointed,
+    /// The round expired or was closed; rewards were forfeited.
+    Forfeited,
+    /// The round is still the current round.
+    RoundActive,
+    /// The round does not match the miner's round.
+    RoundMismatch,
+    /// The round was never finalized; only checkpoint_id was updated.
+    NotFinalized,
+}
+
+/// Per-miner outcome of a batch checkpoint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CheckpointResult {
+    /// The miner authority.
+    pub miner: Pubkey,
+
+    /// What happened to the miner.
+    pub status: CheckpointStatus,
+
+    /// SOL rewards credited.
+    pub rewards_sol: u64,
+
+    /// TOKEN rewards credited (after referral fee).
+    pub rewards_token: u64,
+
+    /// Bot fee taken from the miner.
+    pub bot_fee: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn miner_in_round(round_id: u64) -> Miner {
+        Miner {
+            round_id,
+            checkpoint_id: round_id - 1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn can_forfeit_only_own_ended_round() {
+        let miner = miner_in_round(10);
+        assert!(miner.can_forfeit(10, 11));
+    }
+
+    #[test]
+    fn cannot_forfeit_with_arbitrary_round_id() {
+        // Regression: checkpoint_many took round_id from the caller, so an empty
+        // PDA such as round u64::MAX wiped the rewards of any miner passed in.
+        let miner = miner_in_round(10);
+        assert!(!miner.can_forfeit(u64::MAX, 11));
+        assert!(!miner.can_forfeit(9, 11));
+    }
+
+    #[test]
+    fn cannot_forfeit_current_or_future_round() {
+        let miner = miner_in_round(10);
+        assert!(!miner.can_forfeit(10, 10));
+        assert!(!miner_in_round(12).can_forfeit(12, 11));
+    }
+
+    #[test]
+    fn cannot_forfeit_checkpointed_round() {
+        let mut miner = miner_in_round(10);
+        miner.checkpoint_id = 10;
+        assert!(!miner.can_forfeit(10, 11));
+    }
+
+    #[test]
+    fn owns_ticket_within_first_deploy() {
+        let mut miner = Miner::default();
+        miner.deployed[3] = 100;
+        miner.cumulative[3] = 500;
+        assert!(!miner.owns_ticket(3, 499));
+        assert!(miner.owns_ticket(3, 500));
+        assert!(miner.owns_ticket(3, 599));
+        assert!(!miner.owns_ticket(3, 600));
+    }
+
+    #[test]
+    fn owns_ticket_within_top_up() {
+        // 100 deployed at 500, others deployed 400, then a 50 top-up at 1,000
+        let mut miner = Miner::default();
+        miner.deployed[3] = 150;
+        miner.cumulative[3] = 500;
+        miner.top_up_deployed[3] = 50;
+        miner.top_up_cumulative[3] = 1_000;
+        assert!(miner.owns_ticket(3, 599));
+        assert!(!miner.owns_ticket(3, 600));
+        assert!(!miner.owns_ticket(3, 999));
+        assert!(miner.owns_ticket(3, 1_000));
+        assert!(miner.owns_ticket(3, 1_049));
+        assert!(!miner.owns_ticket(3, 1_050));
+    }
+
+    #[test]
+    fn owns_no_ticket_on_other_squares() {
+        let mut miner = Miner::default();
+        miner.deployed[3] = 100;
+        assert!(miner.owns_ticket(3, 0));
+        assert!(!miner.owns_ticket(4, 0));
+    }
+}
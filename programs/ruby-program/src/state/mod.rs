@@ -1,8 +1,15 @@
 pub mod automation;
 pub mod board;
 pub mod config;
+pub mod emission_schedule;
+pub mod executor;
+pub mod leaderboard;
 pub mod miner;
+pub mod pending_config_change;
+pub mod presale;
 pub mod referral;
+pub mod referral_config;
+pub mod referrer_stats;
 pub mod round;
 pub mod stake;
 pub mod treasury;
@@ -10,8 +17,15 @@ pub mod treasury;
 pub use automation::*;
 pub use board::*;
 pub use config::*;
+pub use emission_schedule::*;
+pub use executor::*;
+pub use leaderboard::*;
 pub use miner::*;
+pub use pending_config_change::*;
+pub use presale::*;
 pub use referral::*;
+pub use referral_config::*;
+pub use referrer_stats::*;
 pub use round::*;
 pub use stake::*;
 pub use treasury::*;
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use super::{ConfigField, ConfigValue};
+
+/// A timelocked config change, one per field
+#[account]
+pub struct PendingConfigChange {
+    /// The config field to change.
+    pub field: ConfigField,
+
+    /// The value the field will be set to.
+    pub new_value: ConfigValue,
+
+    /// The slot at which the change was queued.
+    pub queued_at: u64,
+
+    /// The first slot at which the change can be executed.
+    pub executable_at: u64,
+}
+
+impl PendingConfigChange {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // field
+        ConfigValue::LEN + // new_value
+        8 + // queued_at
+        8; // executable_at
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::constants::PRESALE_RATE;
+
+#[account]
+#[derive(Default)]
+pub struct Presale {
+    /// The slot at which contributions open.
+    pub start_slot: u64,
+
+    /// The slot at which contributions close.
+    pub end_slot: u64,
+
+    /// The address that receives the raised SOL for initial liquidity.
+    pub liquidity_recipient: Pubkey,
+
+    /// The total amount of SOL contributed.
+    pub total_raised: u64,
+
+    /// The total amount of RUBY minted to contributors.
+    pub total_claimed: u64,
+
+    /// The total amount of SOL refunded to contributors after a cancellation.
+    pub total_refunded: u64,
+
+    /// The number of unique contributors.
+    pub contributors: u64,
+
+    /// The status of the presale.
+    pub status: u64,
+}
+
+impl Presale {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // start_slot
+        8 + // end_slot
+        32 + // liquidity_recipient
+        8 + // total_raised
+        8 + // total_claimed
+        8 + // total_refunded
+        8 + // contributors
+        8; // status
+
+    pub fn is_accepting(&self, current_slot: u64) -> bool {
+        PresaleStatus::from_u64(self.status) == PresaleStatus::Open
+            && current_slot >= self.start_slot
+            && current_slot < self.end_slot
+    }
+
+    /// The amount of RUBY owed for a SOL contribution.
+    pub fn token_amount(lamports: u64) -> Option<u64> {
+        lamports.checked_mul(PRESALE_RATE)
+    }
+}
+
+/// Per-wallet presale contribution
+#[account]
+#[derive(Default)]
+pub struct PresaleContribution {
+    /// The wallet that contributed.
+    pub authority: Pubkey,
+
+    /// The amount of SOL contributed.
+    pub amount: u64,
+
+    /// Timestamp of the first contribution.
+    pub created_at: i64,
+}
+
+impl PresaleContribution {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        8 + // amount
+        8; // created_at
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum PresaleStatus {
+    Open,
+    Finalized,
+    Cancelled,
+}
+
+impl PresaleStatus {
+    pub fn from_u64(value: u64) -> Self {
+        match value {
+            1 => PresaleStatus::Finalized,
+            2 => PresaleStatus::Cancelled,
+            _ => PresaleStatus::Open,
+        }
+    }
+}
@@ -18,6 +18,15 @@ pub struct Referral {
 
     /// Timestamp when the referral was created
     pub created_at: i64,
+
+    /// The referrer's own referrer, who earns the second-level share
+    pub second_referrer: Pubkey,
+
+    /// Legacy second-level TOKEN rewards for second_referrer to claim (new ones go to ReferrerStats)
+    pub second_pending_rewards: u64,
+
+    /// Whether the one-time referrer change has been used
+    pub referrer_changed: bool,
 }
 
 impl Referral {
@@ -26,6 +35,9 @@ impl Referral {
         32 + // referrer
         8 +  // pending_rewards
         8 +  // claimed_rewards
-        8;   // created_at
-    // Total: 96 bytes
+        8 +  // created_at
+        32 + // second_referrer
+        8 +  // second_pending_rewards
+        1;   // referrer_changed
+    // Total: 137 bytes
 }
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Referral config - tiered referrer rates, the referrer change window and the protocol sink
+#[account]
+#[derive(Default)]
+pub struct ReferralConfig {
+    /// Referred volume (lamports deployed by referees) needed to reach each tier.
+    pub tier_thresholds: [u64; REFERRAL_TIERS],
+
+    /// Referrer share of referee TOKEN rewards for each tier, in basis points.
+    pub tier_bps: [u64; REFERRAL_TIERS],
+
+    /// Share of referee TOKEN rewards paid to the referrer's own referrer, in basis points.
+    pub second_level_bps: u64,
+
+    /// Seconds after registering in which a referee may change referrer once.
+    pub change_window: i64,
+
+    /// Referrer credited with the base-rate fee of miners without a referral.
+    /// A wallet, not a PDA: its rewards accrue on the ReferrerStats at
+    /// [REFERRER_STATS, protocol_referrer] and it signs claim_referrer_rewards
+    /// to collect them. Defaults to the initialize signer.
+    pub protocol_referrer: Pubkey,
+}
+
+impl ReferralConfig {
+    pub const LEN: usize = 8 + // discriminator
+        (8 * REFERRAL_TIERS) + // tier_thresholds
+        (8 * REFERRAL_TIERS) + // tier_bps
+        8 + // second_level_bps
+        8 + // change_window
+        32; // protocol_referrer
+
+    pub const DEFAULT_TIER_THRESHOLDS: [u64; REFERRAL_TIERS] =
+        [0, 100 * ONE_SOL, 1_000 * ONE_SOL, 10_000 * ONE_SOL];
+
+    pub const DEFAULT_TIER_BPS: [u64; REFERRAL_TIERS] = [REFERRAL_FEE_BPS, 150, 200, 300];
+
+    pub const DEFAULT_SECOND_LEVEL_BPS: u64 = 25;
+
+    /// Start with the default tiers and change window.
+    pub fn init_default(&mut self, protocol_referrer: Pubkey) {
+        self.tier_thresholds = Self::DEFAULT_TIER_THRESHOLDS;
+        self.tier_bps = Self::DEFAULT_TIER_BPS;
+        self.second_level_bps = Self::DEFAULT_SECOND_LEVEL_BPS;
+        self.change_window = REFERRAL_CHANGE_WINDOW;
+        self.protocol_referrer = protocol_referrer;
+    }
+
+    /// Thresholds start at zero and ascend, rates never decrease, and no rate exceeds the cap.
+    pub fn is_valid(&self) -> bool {
+        self.tier_thresholds[0] == 0
+            && self.tier_thresholds.windows(2).all(|w| w[0] < w[1])
+            && self.tier_bps.windows(2).all(|w| w[0] <= w[1])
+            && self.tier_bps[REFERRAL_TIERS - 1] <= MAX_REFERRAL_BPS
+            && self.second_level_bps <= MAX_REFERRAL_BPS
+            && self.change_window >= 0
+            && self.protocol_referrer != Pubkey::default()
+    }
+
+    /// The tier reached with a given referred volume.
+    pub fn tier_for(&self, referred_volume: u64) -> u64 {
+        self.tier_thresholds
+            .iter()
+            .rposition(|&threshold| referred_volume >= threshold)
+            .unwrap_or(0) as u64
+    }
+
+    /// The referrer rate for a tier, in basis points.
+    pub fn bps_for(&self, tier: u64) -> u64 {
+        self.tier_bps[(tier as usize).min(REFERRAL_TIERS - 1)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_config() -> ReferralConfig {
+        let mut config = ReferralConfig::default();
+        config.init_default(Pubkey::new_unique());
+        config
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(default_config().is_valid());
+    }
+
+    #[test]
+    fn tier_for_volume_at_thresholds() {
+        let config = default_config();
+        assert_eq!(config.tier_for(0), 0);
+        assert_eq!(config.tier_for(100 * ONE_SOL - 1), 0);
+        assert_eq!(config.tier_for(100 * ONE_SOL), 1);
+        assert_eq!(config.tier_for(1_000 * ONE_SOL - 1), 1);
+        assert_eq!(config.tier_for(1_000 * ONE_SOL), 2);
+        assert_eq!(config.tier_for(10_000 * ONE_SOL), 3);
+        assert_eq!(config.tier_for(u64::MAX), 3);
+    }
+
+    #[test]
+    fn bps_for_clamps_to_top_tier() {
+        let config = default_config();
+        assert_eq!(config.bps_for(config.tier_for(0)), REFERRAL_FEE_BPS);
+        assert_eq!(config.bps_for(REFERRAL_TIERS as u64), 300);
+    }
+}
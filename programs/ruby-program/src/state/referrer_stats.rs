@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Referrer stats - per-referrer aggregate of volume, tier and rewards
+#[account]
+#[derive(Default)]
+pub struct ReferrerStats {
+    /// The referrer these stats belong to.
+    pub referrer: Pubkey,
+
+    /// Total SOL deployed by this referrer's referees, credited at checkpoint.
+    pub referred_volume: u64,
+
+    /// Current referral tier.
+    pub tier: u64,
+
+    /// TOKEN rewards credited by referee checkpoints, not yet claimed.
+    pub pending_rewards: u64,
+
+    /// Total TOKEN rewards claimed.
+    pub claimed_rewards: u64,
+
+    /// Number of referees currently pointing at this referrer.
+    pub referee_count: u64,
+}
+
+impl ReferrerStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // referrer
+        8 + // referred_volume
+        8 + // tier
+        8 + // pending_rewards
+        8 + // claimed_rewards
+        8; // referee_count
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use solana_nostd_keccak::hashv;
 
 use crate::constants::TOTAL_BOARD;
 
@@ -43,6 +44,15 @@ pub struct Round {
 
     /// The total amount of SOL won by miners for the round.
     pub total_winnings: u64,
+
+    /// The amount of mini-motherlode SOL paid into this round.
+    pub mini_motherlode_sol: u64,
+
+    /// The amount of SOL motherlode paid into this round.
+    pub motherlode_sol: u64,
+
+    /// The total amount of TOKEN credited by checkpoints (including referral fees).
+    pub total_token_credited: u64,
 }
 
 impl Round {
@@ -59,7 +69,24 @@ impl Round {
         8 + // total_deployed
         8 + // total_vaulted
         8 + // total_stake_rewards
-        8; // total_winnings
+        8 + // total_winnings
+        8 + // mini_motherlode_sol
+        8 + // motherlode_sol
+        8; // total_token_credited
+
+    /// Fill the fields a round created before the layout grew gains when it is
+    /// migrated. Its motherlode SOL payouts read as zero, and any TOKEN from its
+    /// reset counts as credited, because earlier checkpoints were never counted.
+    pub fn migrate_legacy(&mut self) {
+        self.total_token_credited = self.top_miner_reward.saturating_add(self.motherlode);
+    }
+
+    /// The TOKEN minted for this round that no checkpoint has credited.
+    pub fn uncredited_token(&self) -> u64 {
+        self.top_miner_reward
+            .saturating_add(self.motherlode)
+            .saturating_sub(self.total_token_credited)
+    }
 
     pub fn rng(&self) -> Option<u64> {
         if self.slot_hash == [0; 32] || self.slot_hash == [u8::MAX; 32] {
@@ -108,4 +135,56 @@ impl Round {
     pub fn did_hit_motherlode(&self, rng: u64) -> bool {
         rng.reverse_bits() % 625 == 0
     }
+
+    /// The SOL motherlode draws from its own hash of the round's entropy, so it is
+    /// independent of `rng`, which picks the winning square, the split and the
+    /// RUBY motherlode.
+    pub fn did_hit_motherlode_sol(&self, odds: u64) -> bool {
+        if odds == 0 {
+            return false;
+        }
+        let hash = hashv(&[&self.slot_hash, b"sol-motherlode"]);
+        let r = u64::from_le_bytes(hash[0..8].try_into().unwrap());
+        r % odds == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_with_words(words: [u64; 4]) -> Round {
+        let mut round = Round::default();
+        for (i, word) in words.iter().enumerate() {
+            round.slot_hash[i * 8..(i + 1) * 8].copy_from_slice(&word.to_le_bytes());
+        }
+        round
+    }
+
+    #[test]
+    fn sol_motherlode_is_not_coupled_to_ruby_motherlode() {
+        // Every round below has the same rng: the same winning square, and all of
+        // them hit the RUBY motherlode. The SOL draw must still vary between them.
+        let rng = (625u64 * 7).reverse_bits();
+        let rounds = 2_000;
+        let mut sol_hits = 0;
+        for m in 1..=rounds {
+            let round = round_with_words([rng, m, m, 0]);
+            let r = round.rng().unwrap();
+            assert_eq!(r, rng);
+            assert!(round.did_hit_motherlode(r));
+            if round.did_hit_motherlode_sol(4) {
+                sol_hits += 1;
+            }
+        }
+        // About 1 in 4, as for any other round
+        assert!((400..600).contains(&sol_hits), "{} SOL hits", sol_hits);
+    }
+
+    #[test]
+    fn sol_motherlode_disabled_with_zero_odds() {
+        let round = round_with_words([1, 2, 3, 4]);
+        assert!(!round.did_hit_motherlode_sol(0));
+        assert!(round.did_hit_motherlode_sol(1));
+    }
 }
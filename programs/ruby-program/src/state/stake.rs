@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
+use crate::constants::*;
+use crate::errors::AppError;
+use crate::events::StakeUnlockedEvent;
 use crate::utils::Numeric;
 use super::Treasury;
 
@@ -32,6 +35,111 @@ pub struct Stake {
 
     /// Deprecated field - no longer used
     pub is_seeker: u64,
+
+    /// The balance that earns rewards: unlocked balance plus locked positions
+    /// scaled by their multipliers.
+    pub weighted_balance: u64,
+
+    /// Lock positions (empty slots have a zero amount).
+    pub locks: [LockPosition; MAX_STAKE_LOCKS],
+
+    /// Unbonding entries waiting out the cooldown (empty slots have a zero amount).
+    /// Unbonding RUBY is no longer part of `balance` and earns no rewards.
+    pub unbonding: [UnbondingEntry; MAX_UNBONDING_ENTRIES],
+
+    /// The timestamp rewards were last accumulated at.
+    pub rewards_updated_at: i64,
+
+    /// The layout version of this account.
+    pub version: u64,
+}
+
+/// A locked part of the stake balance, boosted until it unlocks
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct LockPosition {
+    /// The amount of RUBY locked.
+    pub amount: u64,
+
+    /// The lock period (StakeLockPeriod).
+    pub period: u64,
+
+    /// The timestamp at which the position unlocks.
+    pub unlock_at: i64,
+
+    /// Reward weight of the position, in basis points of its amount.
+    pub multiplier_bps: u64,
+}
+
+/// RUBY on its way out of the stake account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct UnbondingEntry {
+    /// The amount of RUBY unbonding.
+    pub amount: u64,
+
+    /// The timestamp from which the amount can be withdrawn.
+    pub available_at: i64,
+}
+
+impl UnbondingEntry {
+    pub const LEN: usize = 8 * 2;
+
+    pub fn is_empty(&self) -> bool {
+        self.amount == 0
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum StakeLockPeriod {
+    OneMonth,
+    ThreeMonths,
+    SixMonths,
+    TwelveMonths,
+}
+
+impl StakeLockPeriod {
+    pub fn from_u64(value: u64) -> Result<Self> {
+        match value {
+            0 => Ok(StakeLockPeriod::OneMonth),
+            1 => Ok(StakeLockPeriod::ThreeMonths),
+            2 => Ok(StakeLockPeriod::SixMonths),
+            3 => Ok(StakeLockPeriod::TwelveMonths),
+            _ => err!(AppError::InvalidLockPeriod),
+        }
+    }
+
+    /// Lock duration in seconds.
+    pub fn duration(&self) -> i64 {
+        let months = match self {
+            StakeLockPeriod::OneMonth => 1,
+            StakeLockPeriod::ThreeMonths => 3,
+            StakeLockPeriod::SixMonths => 6,
+            StakeLockPeriod::TwelveMonths => 12,
+        };
+        months * STAKE_LOCK_MONTH
+    }
+
+    /// Reward weight, in basis points (10,000 = 1x).
+    pub fn multiplier_bps(&self) -> u64 {
+        match self {
+            StakeLockPeriod::OneMonth => 11_000,
+            StakeLockPeriod::ThreeMonths => 12_500,
+            StakeLockPeriod::SixMonths => 15_000,
+            StakeLockPeriod::TwelveMonths => 20_000,
+        }
+    }
+}
+
+impl LockPosition {
+    pub const LEN: usize = 8 * 4;
+
+    pub fn is_empty(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Reward weight of this position.
+    pub fn weight(&self) -> u64 {
+        ((self.amount as u128 * self.multiplier_bps as u128) / 10_000) as u64
+    }
 }
 
 impl Stake {
@@ -44,14 +152,36 @@ impl Stake {
         16 + // rewards_factor (i128)
         8 + // rewards
         8 + // lifetime_rewards
-        8; // is_seeker (deprecated)
+        8 + // is_seeker (deprecated)
+        8 + // weighted_balance
+        (LockPosition::LEN * MAX_STAKE_LOCKS) + // locks
+        (UnbondingEntry::LEN * MAX_UNBONDING_ENTRIES) + // unbonding
+        8 + // rewards_updated_at
+        8; // version
+
+    /// Fill defaults for fields added since this account's version (they read as
+    /// zero after the account is grown) and bump it to STAKE_VERSION.
+    pub fn migrate(&mut self, now: i64) {
+        if self.version < 1 {
+            // Stakes from before lock positions weigh exactly their balance, as
+            // migrate_treasury counted them in the total stake weight.
+            self.weighted_balance = self.balance;
+            self.rewards_updated_at = now;
+        }
+        if self.version < 2 {
+            // Stakes from before unbonding withdrew instantly, so nothing is queued.
+            self.unbonding = [UnbondingEntry::default(); MAX_UNBONDING_ENTRIES];
+        }
+        self.version = STAKE_VERSION;
+    }
 
-    pub fn claim(&mut self, amount: u64, clock: &Clock, treasury: &Treasury) -> u64 {
-        self.update_rewards(treasury);
+    pub fn claim(&mut self, amount: u64, clock: &Clock, treasury: &mut Treasury) -> Result<u64> {
+        self.update_rewards(clock, treasury)?;
+        self.release_expired_locks(clock, treasury)?;
         let amount = self.rewards.min(amount);
         self.rewards -= amount;
         self.last_claim_at = clock.unix_timestamp;
-        amount
+        Ok(amount)
     }
 
     pub fn deposit(
@@ -60,37 +190,420 @@ impl Stake {
         clock: &Clock,
         treasury: &mut Treasury,
         sender: &TokenAccount,
-    ) -> u64 {
-        self.update_rewards(treasury);
+    ) -> Result<u64> {
+        self.update_rewards(clock, treasury)?;
+        self.release_expired_locks(clock, treasury)?;
         let amount = sender.amount.min(amount);
         self.balance += amount;
         self.last_deposit_at = clock.unix_timestamp;
         treasury.total_staked += amount;
-        amount
+        self.update_weight(treasury)?;
+        Ok(amount)
     }
 
-    pub fn withdraw(&mut self, amount: u64, clock: &Clock, treasury: &mut Treasury) -> u64 {
-        self.update_rewards(treasury);
+    /// Move unlocked balance into the unbonding queue. Returns the amount and
+    /// the slot used.
+    pub fn unbond(
+        &mut self,
+        amount: u64,
+        cooldown: i64,
+        clock: &Clock,
+        treasury: &mut Treasury,
+    ) -> Result<(u64, usize)> {
+        self.update_rewards(clock, treasury)?;
+        self.release_expired_locks(clock, treasury)?;
         let amount = self.balance.min(amount);
+        require!(amount > 0, AppError::InvalidAmount);
+        require!(amount <= self.unlocked_balance(), AppError::StakeLocked);
+        let index = self
+            .unbonding
+            .iter()
+            .position(UnbondingEntry::is_empty)
+            .ok_or(AppError::UnbondingLimitReached)?;
+        self.unbonding[index] = UnbondingEntry {
+            amount,
+            available_at: clock.unix_timestamp + cooldown,
+        };
         self.balance -= amount;
-        self.last_withdraw_at = clock.unix_timestamp;
         treasury.total_staked -= amount;
+        self.update_weight(treasury)?;
+        Ok((amount, index))
+    }
+
+    /// Remove every unbonding entry past its cooldown. Returns the total amount.
+    pub fn withdraw_unbonded(&mut self, clock: &Clock) -> u64 {
+        let mut amount = 0;
+        for entry in self.unbonding.iter_mut() {
+            if !entry.is_empty() && entry.available_at <= clock.unix_timestamp {
+                amount += entry.amount;
+                *entry = UnbondingEntry::default();
+            }
+        }
+        if amount > 0 {
+            self.last_withdraw_at = clock.unix_timestamp;
+        }
         amount
     }
 
-    pub fn update_rewards(&mut self, treasury: &Treasury) {
-        // Accumulate rewards, weighted by stake balance.
+    /// The RUBY held in the unbonding queue.
+    pub fn unbonding_balance(&self) -> u64 {
+        self.unbonding.iter().map(|entry| entry.amount).sum()
+    }
+
+    /// Lock part of the unlocked balance for a period. Returns the slot used.
+    pub fn lock(
+        &mut self,
+        amount: u64,
+        period: StakeLockPeriod,
+        clock: &Clock,
+        treasury: &mut Treasury,
+    ) -> Result<usize> {
+        self.update_rewards(clock, treasury)?;
+        self.release_expired_locks(clock, treasury)?;
+        require!(amount <= self.unlocked_balance(), AppError::InsufficientBalance);
+        let index = self
+            .locks
+            .iter()
+            .position(LockPosition::is_empty)
+            .ok_or(AppError::StakeLockLimitReached)?;
+        self.locks[index] = LockPosition {
+            amount,
+            period: period as u64,
+            unlock_at: clock.unix_timestamp + period.duration(),
+            multiplier_bps: period.multiplier_bps(),
+        };
+        self.update_weight(treasury)?;
+        Ok(index)
+    }
+
+    /// The balance held by lock positions.
+    pub fn locked_balance(&self) -> u64 {
+        self.locks.iter().map(|lock| lock.amount).sum()
+    }
+
+    /// The balance free to withdraw or lock.
+    pub fn unlocked_balance(&self) -> u64 {
+        self.balance.saturating_sub(self.locked_balance())
+    }
+
+    /// Release lock positions that have reached their unlock time. Rewards must be
+    /// updated first so expired positions are only boosted up to `unlock_at`.
+    pub fn release_expired_locks(&mut self, clock: &Clock, treasury: &mut Treasury) -> Result<()> {
+        let mut released = false;
+        for (index, lock) in self.locks.iter_mut().enumerate() {
+            if !lock.is_empty() && lock.unlock_at <= clock.unix_timestamp {
+                emit!(StakeUnlockedEvent {
+                    authority: self.authority,
+                    index: index as u64,
+                    amount: lock.amount,
+                    unlock_at: lock.unlock_at,
+                });
+                *lock = LockPosition::default();
+                released = true;
+            }
+        }
+        if released {
+            self.update_weight(treasury)?;
+        }
+        Ok(())
+    }
+
+    /// Recompute the weighted balance and apply the change to the treasury total.
+    fn update_weight(&mut self, treasury: &mut Treasury) -> Result<()> {
+        let weight = self
+            .locks
+            .iter()
+            .try_fold(self.unlocked_balance(), |acc, lock| acc.checked_add(lock.weight()))
+            .ok_or(AppError::Overflow)?;
+        treasury.total_stake_weight = treasury
+            .total_stake_weight
+            .checked_sub(self.weighted_balance)
+            .and_then(|total| total.checked_add(weight))
+            .ok_or(AppError::Overflow)?;
+        self.weighted_balance = weight;
+        Ok(())
+    }
+
+    pub fn update_rewards(&mut self, clock: &Clock, treasury: &mut Treasury) -> Result<()> {
+        // Accumulate rewards, weighted by the boosted stake balance.
         if treasury.stake_rewards_factor > self.rewards_factor {
             let accumulated_rewards = treasury.stake_rewards_factor - self.rewards_factor;
             if accumulated_rewards < Numeric::ZERO {
                 panic!("Accumulated rewards is negative");
             }
-            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.balance);
-            self.rewards += personal_rewards.to_u64();
-            self.lifetime_rewards += personal_rewards.to_u64();
+            let weight = self.reward_weight(clock.unix_timestamp);
+            let personal_rewards = (accumulated_rewards * Numeric::from_u64(weight)).to_u64();
+            self.rewards += personal_rewards;
+            self.lifetime_rewards += personal_rewards;
+
+            // The treasury set aside rewards for the full weighted balance. The
+            // boost an expired lock did not earn goes to buyback instead of
+            // staying in stake_rw_bl with no one to claim it.
+            let reserved_rewards =
+                (accumulated_rewards * Numeric::from_u64(self.weighted_balance)).to_u64();
+            let forfeited = reserved_rewards.saturating_sub(personal_rewards);
+            if forfeited > 0 {
+                treasury.stake_rw_bl = treasury.stake_rw_bl
+                    .checked_sub(forfeited)
+                    .ok_or(AppError::Underflow)?;
+                treasury.buyback_bl = treasury.buyback_bl
+                    .checked_add(forfeited)
+                    .ok_or(AppError::Overflow)?;
+            }
         }
 
         // Update this stake account's last seen rewards factor.
         self.rewards_factor = treasury.stake_rewards_factor;
+        self.rewards_updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// The weight earning the rewards accumulated since the last update. A lock
+    /// that expired in the meantime keeps its boost only for the part of the
+    /// interval before `unlock_at`. The stake only sees the accumulator at its
+    /// last update and now, not when each round was settled, so this assumes the
+    /// rewards arrived evenly over the interval. Rounds are not evenly spaced,
+    /// so the split is an approximation; release_expired_locks keeps the
+    /// interval short.
+    pub fn reward_weight(&self, now: i64) -> u64 {
+        let elapsed = now - self.rewards_updated_at;
+        if elapsed <= 0 {
+            return self.weighted_balance;
+        }
+        let mut weight = self.weighted_balance;
+        for lock in self.locks.iter() {
+            if lock.is_empty() || lock.unlock_at >= now {
+                continue;
+            }
+            let boost = lock.weight().saturating_sub(lock.amount);
+            let unboosted = now - lock.unlock_at.max(self.rewards_updated_at);
+            let forfeited = (boost as u128 * unboosted as u128) / elapsed as u128;
+            weight = weight.saturating_sub(forfeited as u64);
+        }
+        weight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn clock_at(unix_timestamp: i64) -> Clock {
+        Clock {
+            unix_timestamp,
+            ..Default::default()
+        }
+    }
+
+    fn staked(balance: u64, treasury: &mut Treasury) -> Stake {
+        let mut stake = Stake {
+            authority: Pubkey::new_unique(),
+            balance,
+            ..Default::default()
+        };
+        treasury.total_staked += balance;
+        stake.update_weight(treasury).unwrap();
+        stake
+    }
+
+    #[test]
+    fn update_weight_tracks_treasury_total() {
+        let mut treasury = Treasury::default();
+        let mut a = staked(1_000, &mut treasury);
+        let b = staked(500, &mut treasury);
+        assert_eq!(treasury.total_stake_weight, 1_500);
+
+        a.locks[0] = LockPosition {
+            amount: 400,
+            period: StakeLockPeriod::TwelveMonths as u64,
+            unlock_at: DAY,
+            multiplier_bps: StakeLockPeriod::TwelveMonths.multiplier_bps(),
+        };
+        a.update_weight(&mut treasury).unwrap();
+        assert_eq!(a.weighted_balance, 600 + 800);
+        assert_eq!(treasury.total_stake_weight, a.weighted_balance + b.weighted_balance);
+    }
+
+    #[test]
+    fn update_weight_rejects_inconsistent_total() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+
+        // A total that no longer covers this stake's weight fails instead of wrapping
+        treasury.total_stake_weight = 999;
+        stake.balance = 2_000;
+        assert!(stake.update_weight(&mut treasury).is_err());
+        assert_eq!(stake.weighted_balance, 1_000);
+    }
+
+    #[test]
+    fn migrate_weighs_legacy_stake_by_balance() {
+        let mut treasury = Treasury {
+            total_staked: 1_000,
+            ..Default::default()
+        };
+        treasury.migrate();
+        let mut stake = Stake {
+            balance: 1_000,
+            ..Default::default()
+        };
+        stake.migrate(DAY);
+        assert_eq!(stake.weighted_balance, 1_000);
+        assert_eq!(stake.rewards_updated_at, DAY);
+        assert_eq!(stake.version, STAKE_VERSION);
+
+        assert_eq!(stake.unbonding_balance(), 0);
+
+        // Unbonding everything leaves the treasury weight consistent and waits
+        // out the cooldown like any other stake
+        stake.unbond(1_000, 7 * DAY, &clock_at(DAY), &mut treasury).unwrap();
+        assert_eq!(treasury.total_stake_weight, 0);
+        assert_eq!(stake.withdraw_unbonded(&clock_at(8 * DAY - 1)), 0);
+        assert_eq!(stake.withdraw_unbonded(&clock_at(8 * DAY)), 1_000);
+    }
+
+    #[test]
+    fn lock_boosts_weight() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+
+        let index = stake
+            .lock(500, StakeLockPeriod::SixMonths, &clock_at(0), &mut treasury)
+            .unwrap();
+        let lock = stake.locks[index];
+        assert_eq!(lock.unlock_at, StakeLockPeriod::SixMonths.duration());
+        assert_eq!(stake.locked_balance(), 500);
+        assert_eq!(stake.unlocked_balance(), 500);
+        assert_eq!(stake.weighted_balance, 500 + 750);
+        assert_eq!(treasury.total_stake_weight, 1_250);
+    }
+
+    #[test]
+    fn lock_needs_unlocked_balance() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+        stake.lock(800, StakeLockPeriod::OneMonth, &clock_at(0), &mut treasury).unwrap();
+        assert!(stake.lock(201, StakeLockPeriod::OneMonth, &clock_at(0), &mut treasury).is_err());
+        assert!(stake.lock(200, StakeLockPeriod::OneMonth, &clock_at(0), &mut treasury).is_ok());
+    }
+
+    #[test]
+    fn lock_slots_are_limited() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+        for _ in 0..MAX_STAKE_LOCKS {
+            stake.lock(1, StakeLockPeriod::OneMonth, &clock_at(0), &mut treasury).unwrap();
+        }
+        assert!(stake.lock(1, StakeLockPeriod::OneMonth, &clock_at(0), &mut treasury).is_err());
+    }
+
+    #[test]
+    fn expired_lock_is_released_with_its_boost() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+        stake.lock(1_000, StakeLockPeriod::TwelveMonths, &clock_at(0), &mut treasury).unwrap();
+        assert_eq!(treasury.total_stake_weight, 2_000);
+
+        let unlock_at = StakeLockPeriod::TwelveMonths.duration();
+        stake.release_expired_locks(&clock_at(unlock_at - 1), &mut treasury).unwrap();
+        assert_eq!(stake.locked_balance(), 1_000);
+
+        stake.release_expired_locks(&clock_at(unlock_at), &mut treasury).unwrap();
+        assert_eq!(stake.locked_balance(), 0);
+        assert_eq!(stake.weighted_balance, 1_000);
+        assert_eq!(treasury.total_stake_weight, 1_000);
+    }
+
+    #[test]
+    fn expired_lock_is_boosted_only_until_unlock() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+        stake.lock(1_000, StakeLockPeriod::TwelveMonths, &clock_at(0), &mut treasury).unwrap();
+        let unlock_at = StakeLockPeriod::TwelveMonths.duration();
+
+        // Still locked: the full 2x boost
+        assert_eq!(stake.reward_weight(unlock_at), 2_000);
+        // Untouched for as long again after unlocking: boosted for half the interval
+        assert_eq!(stake.reward_weight(2 * unlock_at), 1_500);
+    }
+
+    #[test]
+    fn forfeited_boost_goes_to_buyback() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+        stake.lock(1_000, StakeLockPeriod::TwelveMonths, &clock_at(0), &mut treasury).unwrap();
+        let unlock_at = StakeLockPeriod::TwelveMonths.duration();
+
+        // One lamport per unit of weight, reserved for the full 2x weight
+        treasury.stake_rewards_factor = Numeric::from_u64(1);
+        treasury.stake_rw_bl = 2_000;
+
+        stake.update_rewards(&clock_at(2 * unlock_at), &mut treasury).unwrap();
+        assert_eq!(stake.rewards, 1_500);
+        assert_eq!(treasury.stake_rw_bl, 1_500);
+        assert_eq!(treasury.buyback_bl, 500);
+    }
+
+    #[test]
+    fn unbond_moves_balance_into_queue() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+
+        let (amount, index) = stake.unbond(400, 7 * DAY, &clock_at(DAY), &mut treasury).unwrap();
+        assert_eq!(amount, 400);
+        assert_eq!(stake.unbonding[index].available_at, 8 * DAY);
+        assert_eq!(stake.balance, 600);
+        assert_eq!(stake.unbonding_balance(), 400);
+        assert_eq!(treasury.total_staked, 600);
+        assert_eq!(treasury.total_stake_weight, 600);
+    }
+
+    #[test]
+    fn unbond_caps_amount_at_balance() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+        let (amount, _) = stake.unbond(u64::MAX, DAY, &clock_at(0), &mut treasury).unwrap();
+        assert_eq!(amount, 1_000);
+        assert!(stake.unbond(1, DAY, &clock_at(0), &mut treasury).is_err());
+    }
+
+    #[test]
+    fn unbond_cannot_touch_locked_balance() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+        stake.lock(700, StakeLockPeriod::OneMonth, &clock_at(0), &mut treasury).unwrap();
+        assert!(stake.unbond(301, DAY, &clock_at(0), &mut treasury).is_err());
+        assert!(stake.unbond(300, DAY, &clock_at(0), &mut treasury).is_ok());
+    }
+
+    #[test]
+    fn unbond_entries_are_limited() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+        for _ in 0..MAX_UNBONDING_ENTRIES {
+            stake.unbond(1, DAY, &clock_at(0), &mut treasury).unwrap();
+        }
+        assert!(stake.unbond(1, DAY, &clock_at(0), &mut treasury).is_err());
+    }
+
+    #[test]
+    fn withdraw_unbonded_waits_for_cooldown() {
+        let mut treasury = Treasury::default();
+        let mut stake = staked(1_000, &mut treasury);
+        stake.unbond(100, 7 * DAY, &clock_at(0), &mut treasury).unwrap();
+        stake.unbond(200, 7 * DAY, &clock_at(DAY), &mut treasury).unwrap();
+
+        assert_eq!(stake.withdraw_unbonded(&clock_at(7 * DAY - 1)), 0);
+        assert_eq!(stake.last_withdraw_at, 0);
+
+        assert_eq!(stake.withdraw_unbonded(&clock_at(7 * DAY)), 100);
+        assert_eq!(stake.last_withdraw_at, 7 * DAY);
+        assert_eq!(stake.unbonding_balance(), 200);
+
+        assert_eq!(stake.withdraw_unbonded(&clock_at(30 * DAY)), 200);
+        assert_eq!(stake.unbonding_balance(), 0);
+        assert!(stake.unbonding.iter().all(UnbondingEntry::is_empty));
     }
 }
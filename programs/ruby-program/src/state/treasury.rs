@@ -1,3 +1,4 @@
+use crate::constants::{MAX_SUPPLY, TREASURY_VERSION};
 use crate::utils::Numeric;
 use anchor_lang::prelude::*;
 
@@ -16,7 +17,7 @@ pub struct Treasury {
     /// The cumulative RUBY distributed to miners, divided by the total unclaimed RUBY.
     pub miner_rewards_factor: Numeric,
 
-    /// The cumulative rewards distributed to stakers, divided by the total stake.
+    /// The cumulative rewards distributed to stakers, divided by the total stake weight.
     pub stake_rewards_factor: Numeric,
 
     /// The current total amount of RUBY staking deposits.
@@ -30,6 +31,22 @@ pub struct Treasury {
 
     /// Total SOL collected for weekly leaderboard rewards.
     pub leaderboard_bl: u64,
+
+    /// The amount of SOL held for staking rewards that has not yet been claimed.
+    pub stake_rw_bl: u64,
+
+    /// The current total reward weight of staking deposits (locks boosted).
+    pub total_stake_weight: u64,
+
+    /// The amount of RUBY owed to presale contributors that has not yet been minted.
+    pub presale_reserved: u64,
+
+    /// Leaderboard SOL not yet in a weekly pool (unpaid ranks and earlier accruals),
+    /// added to the next pool credited at reset.
+    pub leaderboard_carry: u64,
+
+    /// The layout version of this account.
+    pub version: u64,
 }
 
 impl Treasury {
@@ -42,5 +59,51 @@ impl Treasury {
         8 + // total_staked
         8 + // total_unclaimed
         8 + // total_refined
-        8; // leaderboard_bl
+        8 + // leaderboard_bl
+        8 + // stake_rw_bl
+        8 + // total_stake_weight
+        8 + // presale_reserved
+        8 + // leaderboard_carry
+        8; // version
+
+    /// Fill defaults for fields added since this account's version (they read as
+    /// zero after the account is grown) and bump it to TREASURY_VERSION.
+    pub fn migrate(&mut self) {
+        if self.version < 1 {
+            // Stakes from before lock positions weigh exactly their balance.
+            self.total_stake_weight = self.total_staked;
+            // Leaderboard SOL accrued before weekly pools existed goes to the next one.
+            self.leaderboard_carry = self.leaderboard_bl;
+        }
+        self.version = TREASURY_VERSION;
+    }
+
+    /// The amount of RUBY that can still be minted as mining rewards, keeping the
+    /// unminted presale allocation out of reach.
+    pub fn mintable_supply(&self, current_supply: u64) -> u64 {
+        MAX_SUPPLY
+            .saturating_sub(current_supply)
+            .saturating_sub(self.presale_reserved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrate_weights_existing_stake_by_balance() {
+        let mut treasury = Treasury {
+            total_staked: 5_000,
+            ..Default::default()
+        };
+        treasury.migrate();
+        assert_eq!(treasury.total_stake_weight, 5_000);
+        assert_eq!(treasury.version, TREASURY_VERSION);
+
+        // Running it again keeps the weight tracked since the first migration
+        treasury.total_stake_weight = 7_500;
+        treasury.migrate();
+        assert_eq!(treasury.total_stake_weight, 7_500);
+    }
 }
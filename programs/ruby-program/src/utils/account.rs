@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use super::transfer_sol_cpi;
+
+/// Grow a program account to `new_len`, with `payer` covering the extra rent.
+/// Only the rent difference is topped up, so SOL an account holds on top of its
+/// rent (treasury and automation balances) is left untouched. New bytes are
+/// zeroed, so fields appended to the layout read as zero.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?;
+    let top_up = rent
+        .minimum_balance(new_len)
+        .saturating_sub(rent.minimum_balance(account.data_len()));
+    if top_up > 0 {
+        transfer_sol_cpi(payer.clone(), account.clone(), system_program.clone(), top_up)?;
+    }
+
+    account.resize(new_len)?;
+    Ok(())
+}
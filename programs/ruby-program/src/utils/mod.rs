@@ -1,5 +1,7 @@
+pub mod account;
 pub mod numeric;
 pub mod transfer;
 
+pub use account::*;
 pub use numeric::*;
 pub use transfer::*;